@@ -0,0 +1,226 @@
+use crate::scalar::Scalar;
+use duplicate::duplicate;
+
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32;
+
+    // |a - b| <= epsilon
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    // |a - b| <= epsilon, or |a - b| <= max(|a|, |b|) * max_relative
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    // |a - b| <= epsilon, or a and b are at most max_ulps representable
+    // floats apart
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.abs_diff_eq(other, Self::default_epsilon())
+    }
+}
+
+pub fn default_epsilon<A: ApproxEq>(_value: &A) -> A::Epsilon {
+    A::default_epsilon()
+}
+
+#[duplicate(
+   float_type bits_type;
+   [ f32 ] [ i32 ];
+   [ f64 ] [ i64 ];
+)]
+impl ApproxEq for float_type {
+    type Epsilon = float_type;
+
+    fn default_epsilon() -> Self::Epsilon {
+        <float_type as Scalar>::EPSILON
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        <float_type as Scalar>::EPSILON
+    }
+
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        (self - other).abs() <= epsilon
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        if self == other {
+            return true;
+        }
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        let diff = (self - other).abs();
+        if diff <= epsilon {
+            return true;
+        }
+
+        diff <= self.abs().max(other.abs()) * max_relative
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        if self.is_sign_positive() != other.is_sign_positive() {
+            return false;
+        }
+
+        let a = i128::from(self.to_bits() as bits_type);
+        let b = i128::from(other.to_bits() as bits_type);
+        (a - b).abs() <= i128::from(max_ulps)
+    }
+}
+
+impl<A: ApproxEq, const N: usize> ApproxEq for [A; N] {
+    type Epsilon = A::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        A::default_epsilon()
+    }
+
+    fn default_max_relative() -> Self::Epsilon {
+        A::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        A::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(x, y)| x.abs_diff_eq(y, epsilon))
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(x, y)| x.relative_eq(y, epsilon, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(x, y)| x.ulps_eq(y, epsilon, max_ulps))
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx {
+    ($expected:expr, $op:expr, $left:expr, $right:expr, |$l:ident, $r:ident| $cmp:expr) => {
+        match (&$left, &$right) {
+            ($l, $r) => {
+                if $cmp != $expected {
+                    panic!(
+                        "assertion failed: `(left {} right)`\n  left: `{:?}`\n right: `{:?}`",
+                        $op, $l, $r
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx!(true, "≈", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::approx_eq(l, r)
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::__assert_approx!(true, "≈", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::abs_diff_eq(l, r, $epsilon)
+        })
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::__assert_approx!(true, "≈", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::relative_eq(
+                l,
+                r,
+                $crate::approx::default_epsilon(l),
+                $max_relative,
+            )
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::__assert_approx!(true, "≈", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::relative_eq(l, r, $epsilon, $max_relative)
+        })
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::__assert_approx!(true, "≈", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::ulps_eq(l, r, $crate::approx::default_epsilon(l), $max_ulps)
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::__assert_approx!(true, "≈", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::ulps_eq(l, r, $epsilon, $max_ulps)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx!(false, "≉", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::approx_eq(l, r)
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::__assert_approx!(false, "≉", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::abs_diff_eq(l, r, $epsilon)
+        })
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::__assert_approx!(false, "≉", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::relative_eq(
+                l,
+                r,
+                $crate::approx::default_epsilon(l),
+                $max_relative,
+            )
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::__assert_approx!(false, "≉", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::relative_eq(l, r, $epsilon, $max_relative)
+        })
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::__assert_approx!(false, "≉", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::ulps_eq(l, r, $crate::approx::default_epsilon(l), $max_ulps)
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::__assert_approx!(false, "≉", $left, $right, |l, r| {
+            $crate::approx::ApproxEq::ulps_eq(l, r, $epsilon, $max_ulps)
+        })
+    };
+}
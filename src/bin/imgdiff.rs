@@ -0,0 +1,100 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::compare::compare;
+use ray_tracer_challenge::image::ImageFormat;
+use std::convert::TryFrom;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+
+const USAGE: &str = "usage: imgdiff EXPECTED ACTUAL [--tolerance MAX_ERROR] \
+                     [--diff FILE.ppm|png|pfm|hdr]";
+
+struct Options {
+    expected: String,
+    actual: String,
+    tolerance: Option<f64>,
+    diff: Option<(String, ImageFormat)>,
+}
+
+fn parse_options() -> Result<Options, Box<dyn Error>> {
+    let mut files = Vec::new();
+    let mut tolerance = None;
+    let mut diff = None;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            files.push(arg);
+            continue;
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg))?;
+
+        match arg.as_str() {
+            "--tolerance" => tolerance = Some(value.parse()?),
+            "--diff" => {
+                let format = ImageFormat::from_path(&value)
+                    .ok_or_else(|| format!("unknown image format of {}", value))?;
+                diff = Some((value, format));
+            }
+            _ => return Err(format!("unknown option {}", arg).into()),
+        }
+    }
+
+    match <[String; 2]>::try_from(files) {
+        Ok([expected, actual]) => Ok(Options {
+            expected,
+            actual,
+            tolerance,
+            diff,
+        }),
+        Err(_) => Err("expected two images".into()),
+    }
+}
+
+fn load(path: &str) -> Result<Canvas, Box<dyn Error>> {
+    let bytes = fs::read(path).map_err(|err| format!("could not read {}: {}", path, err))?;
+    Ok(Canvas::decode(&bytes).map_err(|err| format!("could not decode {}: {}", path, err))?)
+}
+
+// exits with 0 when the images match, 1 when they differ and 2 on errors
+fn main() {
+    let options = parse_options().unwrap_or_else(|err| {
+        eprintln!("{}\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let comparison = load(&options.expected)
+        .and_then(|expected| Ok((expected, load(&options.actual)?)))
+        .and_then(|(expected, actual)| Ok(compare(&expected, &actual)?))
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(2);
+        });
+
+    println!("{}", comparison);
+
+    if let Some((diff, format)) = &options.diff {
+        let bytes = comparison.heatmap().encode(*format).unwrap_or_else(|err| {
+            eprintln!("could not encode {}: {}", diff, err);
+            process::exit(2);
+        });
+
+        if let Err(err) = fs::write(diff, bytes) {
+            eprintln!("could not write {}: {}", diff, err);
+            process::exit(2);
+        }
+    }
+
+    let matches = match options.tolerance {
+        Some(tolerance) => comparison.within(tolerance),
+        None => comparison.is_match(),
+    };
+
+    if !matches {
+        process::exit(1);
+    }
+}
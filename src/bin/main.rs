@@ -1,21 +1,123 @@
-use ray_tracer_challenge::projectile::{Environment, Projectile};
-use ray_tracer_challenge::tuples::{Point, Value, Vector};
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::image::ImageFormat;
+use ray_tracer_challenge::projectile::{plot_trajectory, Environment, Projectile};
+use ray_tracer_challenge::tuples::{Color, Point, Vector};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+
+const USAGE: &str = "usage: main [--width N] [--height N] [--velocity SCALE] \
+                     [--background HEX] [--color HEX] [--axes HEX] [--output FILE.ppm|png|pfm|hdr]";
+
+struct Options {
+    width: usize,
+    height: usize,
+    velocity: f64,
+    background: Color,
+    color: Color,
+    axes: Color,
+    output: String,
+    format: ImageFormat,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            width: 900,
+            height: 550,
+            velocity: 11.25,
+            background: Color::black(),
+            color: Color::new(1.0, 0.0, 0.0),
+            axes: Color::new(0.5, 0.5, 0.5),
+            output: "projectile.ppm".to_string(),
+            format: ImageFormat::Ppm,
+        }
+    }
+}
+
+fn parse_options() -> Result<Options, Box<dyn Error>> {
+    let mut options = Options::default();
+    let mut args = env::args().skip(1);
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--width" => options.width = value.parse()?,
+            "--height" => options.height = value.parse()?,
+            "--velocity" => {
+                options.velocity = value.parse()?;
+                if !options.velocity.is_finite() {
+                    return Err(format!("invalid velocity {}", value).into());
+                }
+            }
+            "--background" => options.background = Color::from_hex(&value)?,
+            "--color" => options.color = Color::from_hex(&value)?,
+            "--axes" => options.axes = Color::from_hex(&value)?,
+            "--output" => {
+                options.format = ImageFormat::from_path(&value)
+                    .ok_or_else(|| format!("unknown image format of {}", value))?;
+                options.output = value;
+            }
+            _ => return Err(format!("unknown option {}", flag).into()),
+        }
+    }
+
+    Ok(options)
+}
+
+// axes along the left and bottom edges, with a tick every 50 units
+fn draw_axes(canvas: &mut Canvas, color: Color) {
+    const TICK_SPACING: usize = 50;
+    const TICK_LENGTH: isize = 4;
+
+    let right = canvas.width() as isize - 1;
+    let bottom = canvas.height() as isize - 1;
+
+    canvas.draw_polyline(&[(0, 0), (0, bottom), (right, bottom)], color, 1.0);
+
+    for x in (TICK_SPACING..canvas.width()).step_by(TICK_SPACING) {
+        let x = x as isize;
+        canvas.draw_line((x, bottom), (x, bottom - TICK_LENGTH), color, 1.0);
+    }
+    for y in (TICK_SPACING..canvas.height()).step_by(TICK_SPACING) {
+        let y = bottom - y as isize;
+        canvas.draw_line((0, y), (TICK_LENGTH, y), color, 1.0);
+    }
+}
 
 fn main() {
-    let mut p = Projectile::new(
-        Point::new(vec![0.0, 1.0, 0.0]),
-        Vector::new(vec![1.0, 1.0, 0.0]).normalize() * 1.0,
-    );
-    let e = Environment::new(
-        Vector::new(vec![0.0, -0.1, 0.0]),
-        Vector::new(vec![-0.01, 0.0, 0.0]),
+    let options = parse_options().unwrap_or_else(|err| {
+        eprintln!("{}\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let p = Projectile::new(
+        Point::new(0.0, 1.0, 0.0),
+        Vector::new(1.0, 1.8, 0.0).normalize() * options.velocity,
     );
-    let mut count = 0;
+    let e = Environment::new(Vector::new(0.0, -0.1, 0.0), Vector::new(-0.01, 0.0, 0.0));
+
+    let mut canvas = Canvas::new(options.width, options.height);
+    canvas.fill(options.background);
+    draw_axes(&mut canvas, options.axes);
 
-    while p.position.value()[1] > 0.0 {
-        p.tick(&e);
-        count += 1;
-        println!("{:?}", p.position.value());
+    let trajectory: Vec<Point> = p.trajectory(&e).collect();
+    plot_trajectory(&mut canvas, trajectory.iter().copied(), options.color);
+
+    let bytes = canvas.encode(options.format).unwrap_or_else(|err| {
+        eprintln!("could not encode {}: {}", options.output, err);
+        process::exit(1);
+    });
+
+    if let Err(err) = fs::write(&options.output, bytes) {
+        eprintln!("could not write {}: {}", options.output, err);
+        process::exit(1);
     }
-    println!("number of ticks: {}", &count);
+
+    println!("number of ticks: {}", trajectory.len());
+    println!("wrote {}", options.output);
 }
@@ -0,0 +1,82 @@
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+
+#[derive(Debug, Clone)]
+pub struct Canvas<T = f64> {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color<T>>,
+}
+
+impl<T: Scalar> Canvas<T> {
+    pub fn new(width: usize, height: usize) -> Self {
+        Canvas {
+            width,
+            height,
+            pixels: vec![Color::black(); width * height],
+        }
+    }
+
+    // pixels are row-major, starting at the top left corner
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<Color<T>>) -> Result<Self> {
+        if pixels.len() != width * height {
+            return Err(Error::DimensionMismatch {
+                expected: width * height,
+                found: pixels.len(),
+            });
+        }
+
+        Ok(Canvas {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[Color<T>] {
+        &self.pixels
+    }
+
+    pub fn pixel_at(&self, x: usize, y: usize) -> Color<T> {
+        self.pixels[self.index(x, y)]
+    }
+
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Color<T>) {
+        let index = self.index(x, y);
+        self.pixels[index] = color;
+    }
+
+    pub fn fill(&mut self, color: Color<T>) {
+        for pixel in self.pixels.iter_mut() {
+            *pixel = color;
+        }
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel ({}, {}) is outside of the {}x{} canvas",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+
+        y * self.width + x
+    }
+}
+
+impl<T: Scalar> PartialEq for Canvas<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.pixels == other.pixels
+    }
+}
@@ -0,0 +1,162 @@
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Comparison<T = f64> {
+    width: usize,
+    height: usize,
+    // the largest absolute channel difference of each pixel
+    errors: Vec<T>,
+    mismatched_pixels: usize,
+    max_error: T,
+    mean_error: T,
+    mean_squared_error: T,
+}
+
+// compares pixel by pixel; a pixel mismatches when its colors are not equal
+// under the approximate equality of Color. Channels whose difference is not
+// finite, such as NaNs in either image, count as an infinite error.
+pub fn compare<T: Scalar>(expected: &Canvas<T>, actual: &Canvas<T>) -> Result<Comparison<T>> {
+    let size = |canvas: &Canvas<T>| (canvas.width(), canvas.height());
+
+    if size(expected) != size(actual) {
+        return Err(Error::SizeMismatch {
+            expected: size(expected),
+            found: size(actual),
+        });
+    }
+
+    let mut errors = Vec::with_capacity(expected.pixels().len());
+    let mut mismatched_pixels = 0;
+    let mut max_error = T::ZERO;
+    let mut sum = 0.0;
+    let mut sum_of_squares = 0.0;
+
+    for (e, a) in expected.pixels().iter().zip(actual.pixels().iter()) {
+        if e != a {
+            mismatched_pixels += 1;
+        }
+
+        let mut pixel_error = T::ZERO;
+        for difference in [
+            e.red() - a.red(),
+            e.green() - a.green(),
+            e.blue() - a.blue(),
+        ] {
+            let difference = if difference.to_f64().is_finite() {
+                difference.abs()
+            } else {
+                T::from_f64(f64::INFINITY)
+            };
+            if difference > pixel_error {
+                pixel_error = difference;
+            }
+            sum += difference.to_f64();
+            sum_of_squares += difference.to_f64() * difference.to_f64();
+        }
+
+        if pixel_error > max_error {
+            max_error = pixel_error;
+        }
+        errors.push(pixel_error);
+    }
+
+    let channels = (errors.len() * 3).max(1) as f64;
+
+    Ok(Comparison {
+        width: expected.width(),
+        height: expected.height(),
+        errors,
+        mismatched_pixels,
+        max_error,
+        mean_error: T::from_f64(sum / channels),
+        mean_squared_error: T::from_f64(sum_of_squares / channels),
+    })
+}
+
+impl<T: Scalar> Comparison<T> {
+    pub fn mismatched_pixels(&self) -> usize {
+        self.mismatched_pixels
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+
+    // true when no channel differs by more than tolerance
+    pub fn within(&self, tolerance: T) -> bool {
+        self.max_error <= tolerance
+    }
+
+    pub fn max_error(&self) -> T {
+        self.max_error
+    }
+
+    // mean absolute difference over all channels
+    pub fn mean_error(&self) -> T {
+        self.mean_error
+    }
+
+    pub fn mean_squared_error(&self) -> T {
+        self.mean_squared_error
+    }
+
+    // peak signal-to-noise ratio in decibels for a peak of 1.0; identical
+    // images have an infinite PSNR
+    pub fn psnr(&self) -> f64 {
+        let mse = self.mean_squared_error.to_f64();
+
+        if mse == 0.0 {
+            f64::INFINITY
+        } else {
+            -10.0 * mse.log10()
+        }
+    }
+
+    // matching pixels are black, mismatched ones go from red through yellow
+    // to white as their error approaches the maximum error; infinite errors
+    // are white
+    pub fn heatmap(&self) -> Canvas<T> {
+        let max_error = self.max_error.to_f64();
+
+        let pixels = self
+            .errors
+            .iter()
+            .map(|&error| {
+                let error = error.to_f64();
+                if error <= T::EPSILON.to_f64() {
+                    return Color::black();
+                }
+
+                let ratio = if error.is_infinite() {
+                    1.0
+                } else {
+                    error / max_error
+                };
+                let t = (1.0 + 2.0 * ratio) / 3.0;
+                let ramp = |offset: f64| T::from_f64((3.0 * t - offset).clamp(0.0, 1.0));
+                Color::new(ramp(0.0), ramp(1.0), ramp(2.0))
+            })
+            .collect();
+
+        // one error per pixel of the compared canvases
+        Canvas::from_pixels(self.width, self.height, pixels).unwrap()
+    }
+}
+
+impl<T: Scalar> fmt::Display for Comparison<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} pixels differ, max error {:.6}, mean error {:.6}, PSNR {:.2} dB",
+            self.mismatched_pixels,
+            self.errors.len(),
+            self.max_error.to_f64(),
+            self.mean_error.to_f64(),
+            self.psnr()
+        )
+    }
+}
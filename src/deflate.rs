@@ -0,0 +1,510 @@
+use crate::error::{Error, Result};
+
+// DEFLATE (RFC 1951) and the zlib container (RFC 1950). Compression emits a
+// single fixed-Huffman block with greedy LZ77 matching; decompression handles
+// stored, fixed and dynamic blocks.
+
+const WINDOW_SIZE: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const MAX_CHAIN: usize = 64;
+const HASH_BITS: usize = 15;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+pub fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+
+    // 5552 is the largest run that cannot overflow b before the reduction
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+
+    (b << 16) | a
+}
+
+pub fn zlib_compress(bytes: &[u8]) -> Vec<u8> {
+    // 32K window, deflate, fastest compression level; 0x7801 % 31 == 0
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(bytes));
+    out.extend_from_slice(&adler32(bytes).to_be_bytes());
+    out
+}
+
+pub fn zlib_decompress(bytes: &[u8]) -> Result<Vec<u8>> {
+    if bytes.len() < 6 {
+        return Err(malformed("zlib stream is too short"));
+    }
+
+    let (cmf, flg) = (bytes[0], bytes[1]);
+    if cmf & 0x0f != 8 || cmf >> 4 > 7 {
+        return Err(malformed("unsupported zlib compression method"));
+    }
+    if (u16::from(cmf) << 8 | u16::from(flg)) % 31 != 0 {
+        return Err(malformed("invalid zlib header check bits"));
+    }
+    if flg & 0x20 != 0 {
+        return Err(malformed("zlib preset dictionaries are not supported"));
+    }
+
+    let (data, out) = inflate_prefix(&bytes[2..])?;
+    let trailer = &bytes[2 + data..];
+
+    if trailer.len() < 4 {
+        return Err(malformed("missing zlib checksum"));
+    }
+    let checksum = u32::from_be_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+    if checksum != adler32(&out) {
+        return Err(malformed("zlib checksum mismatch"));
+    }
+
+    Ok(out)
+}
+
+pub fn deflate(bytes: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter::default();
+
+    // final block, fixed Huffman codes
+    writer.write(1, 1);
+    writer.write(1, 2);
+
+    let mut head = vec![usize::MAX; 1 << HASH_BITS];
+    let mut previous = vec![usize::MAX; WINDOW_SIZE];
+    let mut position = 0;
+
+    while position < bytes.len() {
+        let (length, distance) = longest_match(bytes, position, &head, &previous);
+
+        if length >= MIN_MATCH {
+            write_length(&mut writer, length);
+            write_distance(&mut writer, distance);
+        } else {
+            write_literal_length_symbol(&mut writer, u16::from(bytes[position]));
+        }
+
+        for p in position..position + length.max(1) {
+            if p + MIN_MATCH <= bytes.len() {
+                let h = hash(&bytes[p..]);
+                previous[p % WINDOW_SIZE] = head[h];
+                head[h] = p;
+            }
+        }
+        position += length.max(1);
+    }
+
+    write_literal_length_symbol(&mut writer, 256);
+    writer.finish()
+}
+
+pub fn inflate(bytes: &[u8]) -> Result<Vec<u8>> {
+    inflate_prefix(bytes).map(|(_, out)| out)
+}
+
+// returns the number of bytes the deflate stream occupied and its contents
+fn inflate_prefix(bytes: &[u8]) -> Result<(usize, Vec<u8>)> {
+    let mut reader = BitReader::new(bytes);
+    let mut out = Vec::new();
+
+    loop {
+        let last = reader.read(1)? == 1;
+
+        match reader.read(2)? {
+            0 => inflate_stored(&mut reader, &mut out)?,
+            1 => {
+                let (literals, distances) = fixed_codes();
+                inflate_block(&mut reader, &mut out, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+                inflate_block(&mut reader, &mut out, &literals, &distances)?;
+            }
+            _ => return Err(malformed("invalid block type")),
+        }
+
+        if last {
+            break;
+        }
+    }
+
+    Ok((reader.consumed(), out))
+}
+
+fn inflate_stored(reader: &mut BitReader, out: &mut Vec<u8>) -> Result<()> {
+    reader.align();
+
+    let length = reader.read(16)?;
+    let complement = reader.read(16)?;
+    if length != !complement & 0xffff {
+        return Err(malformed("stored block length mismatch"));
+    }
+
+    out.extend_from_slice(reader.bytes(length as usize)?);
+    Ok(())
+}
+
+fn inflate_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<()> {
+    loop {
+        let symbol = literals.decode(reader)?;
+
+        if symbol < 256 {
+            out.push(symbol as u8);
+            continue;
+        }
+        if symbol == 256 {
+            return Ok(());
+        }
+
+        let index = symbol - 257;
+        if index >= LENGTH_BASE.len() {
+            return Err(malformed("invalid length symbol"));
+        }
+        let length =
+            usize::from(LENGTH_BASE[index]) + reader.read(LENGTH_EXTRA[index].into())? as usize;
+
+        let index = distances.decode(reader)?;
+        if index >= DISTANCE_BASE.len() {
+            return Err(malformed("invalid distance symbol"));
+        }
+        let distance =
+            usize::from(DISTANCE_BASE[index]) + reader.read(DISTANCE_EXTRA[index].into())? as usize;
+
+        if distance > out.len() {
+            return Err(malformed("distance reaches before the start of the output"));
+        }
+
+        let start = out.len() - distance;
+        for i in 0..length {
+            out.push(out[start + i]);
+        }
+    }
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+
+    // both tables are complete by construction
+    (
+        Huffman::new(&lengths).unwrap(),
+        Huffman::new(&[5; 30]).unwrap(),
+    )
+}
+
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman)> {
+    let literal_count = reader.read(5)? as usize + 257;
+    let distance_count = reader.read(5)? as usize + 1;
+    let code_length_count = reader.read(4)? as usize + 4;
+
+    if literal_count > 286 || distance_count > 30 {
+        return Err(malformed("too many length or distance codes"));
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for &index in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_lengths[index] = reader.read(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths)?;
+
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let symbol = code_length_code.decode(reader)?;
+
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths
+                    .last()
+                    .ok_or_else(|| malformed("repeat without a previous length"))?;
+                (previous, 3 + reader.read(2)? as usize)
+            }
+            17 => (0, 3 + reader.read(3)? as usize),
+            _ => (0, 11 + reader.read(7)? as usize),
+        };
+
+        if lengths.len() + repeat > literal_count + distance_count {
+            return Err(malformed("code lengths overflow the alphabet"));
+        }
+        lengths.extend(std::iter::repeat_n(value, repeat));
+    }
+
+    if lengths[256] == 0 {
+        return Err(malformed("missing end of block code"));
+    }
+
+    Ok((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..])?,
+    ))
+}
+
+// canonical Huffman code, decoded one bit at a time
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self> {
+        let mut counts = [0u16; 16];
+        for &length in lengths {
+            counts[usize::from(length)] += 1;
+        }
+        counts[0] = 0;
+
+        let mut left = 1i32;
+        for &count in counts.iter().skip(1) {
+            left = (left << 1) - i32::from(count);
+            if left < 0 {
+                return Err(malformed("over-subscribed Huffman code"));
+            }
+        }
+
+        let mut offsets = [0u16; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                let offset = &mut offsets[usize::from(length)];
+                symbols[usize::from(*offset)] = symbol as u16;
+                *offset += 1;
+            }
+        }
+
+        Ok(Huffman { counts, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<usize> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+
+        for &count in self.counts.iter().skip(1) {
+            code |= reader.read(1)? as i32;
+            let count = i32::from(count);
+
+            if code - count < first {
+                return Ok(usize::from(self.symbols[(index + code - first) as usize]));
+            }
+
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+
+        Err(malformed("invalid Huffman code"))
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    bit: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        BitReader {
+            bytes,
+            position: 0,
+            bit: 0,
+        }
+    }
+
+    fn read(&mut self, count: u32) -> Result<u32> {
+        let mut value = 0;
+
+        for i in 0..count {
+            let byte = self
+                .bytes
+                .get(self.position)
+                .ok_or_else(|| malformed("unexpected end of stream"))?;
+
+            value |= u32::from((byte >> self.bit) & 1) << i;
+
+            self.bit += 1;
+            if self.bit == 8 {
+                self.bit = 0;
+                self.position += 1;
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn align(&mut self) {
+        if self.bit != 0 {
+            self.bit = 0;
+            self.position += 1;
+        }
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .bytes
+            .get(self.position..self.position + count)
+            .ok_or_else(|| malformed("unexpected end of stream"))?;
+
+        self.position += count;
+        Ok(bytes)
+    }
+
+    fn consumed(&self) -> usize {
+        self.position + usize::from(self.bit != 0)
+    }
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    current: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    // least significant bit first
+    fn write(&mut self, value: u32, count: u32) {
+        self.current |= value << self.count;
+        self.count += count;
+
+        while self.count >= 8 {
+            self.bytes.push(self.current as u8);
+            self.current >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    // Huffman codes are packed starting with their most significant bit
+    fn write_code(&mut self, code: u32, length: u32) {
+        let reversed = code.reverse_bits() >> (32 - length);
+        self.write(reversed, length);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.current as u8);
+        }
+        self.bytes
+    }
+}
+
+fn write_literal_length_symbol(writer: &mut BitWriter, symbol: u16) {
+    let symbol = u32::from(symbol);
+
+    match symbol {
+        0..=143 => writer.write_code(0x30 + symbol, 8),
+        144..=255 => writer.write_code(0x190 + symbol - 144, 9),
+        256..=279 => writer.write_code(symbol - 256, 7),
+        _ => writer.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_length(writer: &mut BitWriter, length: usize) {
+    let index = LENGTH_BASE
+        .iter()
+        .rposition(|&base| usize::from(base) <= length)
+        .unwrap_or(0);
+
+    write_literal_length_symbol(writer, 257 + index as u16);
+    writer.write(
+        (length - usize::from(LENGTH_BASE[index])) as u32,
+        LENGTH_EXTRA[index].into(),
+    );
+}
+
+fn write_distance(writer: &mut BitWriter, distance: usize) {
+    let index = DISTANCE_BASE
+        .iter()
+        .rposition(|&base| usize::from(base) <= distance)
+        .unwrap_or(0);
+
+    writer.write_code(index as u32, 5);
+    writer.write(
+        (distance - usize::from(DISTANCE_BASE[index])) as u32,
+        DISTANCE_EXTRA[index].into(),
+    );
+}
+
+fn hash(bytes: &[u8]) -> usize {
+    let value = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+    (value.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+}
+
+fn longest_match(
+    bytes: &[u8],
+    position: usize,
+    head: &[usize],
+    previous: &[usize],
+) -> (usize, usize) {
+    if position + MIN_MATCH > bytes.len() {
+        return (0, 0);
+    }
+
+    let max_length = MAX_MATCH.min(bytes.len() - position);
+    let (mut best_length, mut best_distance) = (0, 0);
+    let mut candidate = head[hash(&bytes[position..])];
+
+    for _ in 0..MAX_CHAIN {
+        if candidate == usize::MAX || position - candidate > WINDOW_SIZE - 1 {
+            break;
+        }
+
+        let length = bytes[candidate..]
+            .iter()
+            .zip(bytes[position..position + max_length].iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        if length > best_length {
+            best_length = length;
+            best_distance = position - candidate;
+
+            if length == max_length {
+                break;
+            }
+        }
+
+        let next = previous[candidate % WINDOW_SIZE];
+        if next == usize::MAX || next >= candidate {
+            break;
+        }
+        candidate = next;
+    }
+
+    (best_length, best_distance)
+}
+
+fn malformed(reason: &str) -> Error {
+    Error::MalformedDeflate(reason.to_string())
+}
@@ -0,0 +1,238 @@
+use crate::canvas::Canvas;
+use crate::scalar::Scalar;
+use std::sync::OnceLock;
+
+const BAYER: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+const BLUE_NOISE_SIZE: usize = 32;
+const BLUE_NOISE_SIGMA: f64 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    // rounds every channel to the nearest level
+    None,
+    // diffuses the rounding error onto the pixels to the right and below
+    FloydSteinberg,
+    // ordered dithering with an 8x8 Bayer threshold matrix
+    Bayer,
+    // ordered dithering with a 32x32 void-and-cluster threshold tile
+    BlueNoise,
+}
+
+impl<T: Scalar> Canvas<T> {
+    // clamps to [0, 1] and quantizes every pixel to 8 bits per channel
+    pub fn to_rgb8(&self, dither: Dither) -> Vec<[u8; 3]> {
+        match dither {
+            Dither::None => self.pixels().iter().map(|pixel| pixel.to_rgb8()).collect(),
+            Dither::FloydSteinberg => self.error_diffused(),
+            Dither::Bayer => self.ordered(|x, y| (f64::from(BAYER[y % 8][x % 8]) + 0.5) / 64.0),
+            Dither::BlueNoise => {
+                let tile = blue_noise();
+                self.ordered(|x, y| {
+                    tile[(y % BLUE_NOISE_SIZE) * BLUE_NOISE_SIZE + x % BLUE_NOISE_SIZE]
+                })
+            }
+        }
+    }
+
+    // threshold(x, y) in (0, 1) replaces the 0.5 of plain rounding
+    fn ordered(&self, threshold: impl Fn(usize, usize) -> f64) -> Vec<[u8; 3]> {
+        let width = self.width().max(1);
+
+        self.pixels()
+            .iter()
+            .enumerate()
+            .map(|(i, pixel)| {
+                let threshold = threshold(i % width, i / width);
+                let quantize = |c: T| {
+                    (c.to_f64().clamp(0.0, 1.0) * 255.0 + threshold)
+                        .floor()
+                        .min(255.0) as u8
+                };
+
+                [
+                    quantize(pixel.red()),
+                    quantize(pixel.green()),
+                    quantize(pixel.blue()),
+                ]
+            })
+            .collect()
+    }
+
+    fn error_diffused(&self) -> Vec<[u8; 3]> {
+        let (width, height) = (self.width(), self.height());
+        let mut values: Vec<[f64; 3]> = self
+            .pixels()
+            .iter()
+            .map(|pixel| {
+                [pixel.red(), pixel.green(), pixel.blue()]
+                    .map(|c| c.to_f64().clamp(0.0, 1.0) * 255.0)
+            })
+            .collect();
+        let mut rgb = Vec::with_capacity(values.len());
+
+        for y in 0..height {
+            for x in 0..width {
+                let value = values[y * width + x];
+                let quantized = value.map(|c| c.round().clamp(0.0, 255.0));
+                rgb.push(quantized.map(|c| c as u8));
+
+                let mut spread = |dx: isize, dy: usize, weight: f64| {
+                    let (nx, ny) = (x as isize + dx, y + dy);
+                    if nx < 0 || nx as usize >= width || ny >= height {
+                        return;
+                    }
+
+                    let neighbor = &mut values[ny * width + nx as usize];
+                    for channel in 0..3 {
+                        neighbor[channel] += (value[channel] - quantized[channel]) * weight;
+                    }
+                };
+
+                spread(1, 0, 7.0 / 16.0);
+                spread(-1, 1, 3.0 / 16.0);
+                spread(0, 1, 5.0 / 16.0);
+                spread(1, 1, 1.0 / 16.0);
+            }
+        }
+
+        rgb
+    }
+}
+
+// thresholds in (0, 1) of a tileable blue-noise pattern, generated once
+fn blue_noise() -> &'static [f64] {
+    static TILE: OnceLock<Vec<f64>> = OnceLock::new();
+
+    TILE.get_or_init(|| {
+        let ranks = void_and_cluster(BLUE_NOISE_SIZE, BLUE_NOISE_SIGMA);
+        let count = ranks.len() as f64;
+
+        ranks
+            .into_iter()
+            .map(|rank| (rank as f64 + 0.5) / count)
+            .collect()
+    })
+}
+
+// Ulichney's void-and-cluster method: ranks every cell of a size x size torus
+// so that each prefix of the ranking is spread as evenly as possible
+fn void_and_cluster(size: usize, sigma: f64) -> Vec<usize> {
+    let count = size * size;
+
+    // gaussian weight of every toroidal offset
+    let kernel: Vec<f64> = (0..count)
+        .map(|offset| {
+            let wrap = |d: usize| d.min(size - d) as f64;
+            let (dx, dy) = (wrap(offset % size), wrap(offset / size));
+            (-(dx * dx + dy * dy) / (2.0 * sigma * sigma)).exp()
+        })
+        .collect();
+
+    let mut pattern = Pattern {
+        size,
+        kernel,
+        ones: vec![false; count],
+        energy: vec![0.0; count],
+    };
+
+    // a deterministic pseudo-random initial pattern with a tenth of the cells set
+    let mut state = 0x2545_f491_u32;
+    let mut initial = 0;
+    while initial < count / 10 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        let cell = state as usize % count;
+        if !pattern.ones[cell] {
+            pattern.toggle(cell);
+            initial += 1;
+        }
+    }
+
+    // move ones from the tightest cluster to the largest void until stable
+    loop {
+        let cluster = pattern.tightest_cluster();
+        pattern.toggle(cluster);
+        let void = pattern.largest_void();
+        pattern.toggle(void);
+
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0; count];
+
+    // ranks below the initial count by removing clusters from a copy
+    let mut removing = pattern.clone();
+    for rank in (0..initial).rev() {
+        let cluster = removing.tightest_cluster();
+        removing.toggle(cluster);
+        ranks[cluster] = rank;
+    }
+
+    // the rest by filling voids
+    for rank in initial..count {
+        let void = pattern.largest_void();
+        pattern.toggle(void);
+        ranks[void] = rank;
+    }
+
+    ranks
+}
+
+#[derive(Clone)]
+struct Pattern {
+    size: usize,
+    kernel: Vec<f64>,
+    ones: Vec<bool>,
+    // the sum of the kernel weights of all set cells at every cell
+    energy: Vec<f64>,
+}
+
+impl Pattern {
+    fn toggle(&mut self, cell: usize) {
+        let sign = if self.ones[cell] { -1.0 } else { 1.0 };
+        self.ones[cell] = !self.ones[cell];
+
+        let (cx, cy) = (cell % self.size, cell / self.size);
+        for (i, energy) in self.energy.iter_mut().enumerate() {
+            let dx = (i % self.size + self.size - cx) % self.size;
+            let dy = (i / self.size + self.size - cy) % self.size;
+            *energy += sign * self.kernel[dy * self.size + dx];
+        }
+    }
+
+    fn tightest_cluster(&self) -> usize {
+        self.extreme(true, |a, b| a > b)
+    }
+
+    fn largest_void(&self) -> usize {
+        self.extreme(false, |a, b| a < b)
+    }
+
+    fn extreme(&self, ones: bool, better: impl Fn(f64, f64) -> bool) -> usize {
+        let mut best: Option<usize> = None;
+
+        for (cell, &energy) in self.energy.iter().enumerate() {
+            if self.ones[cell] == ones && best.is_none_or(|best| better(energy, self.energy[best]))
+            {
+                best = Some(cell);
+            }
+        }
+
+        // both kinds of cells exist while the pattern is being built
+        best.unwrap()
+    }
+}
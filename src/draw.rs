@@ -0,0 +1,303 @@
+use crate::canvas::Canvas;
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+
+// Drawing clips against the canvas: the parts of a shape that fall outside of
+// it are skipped, so coordinates are signed. An alpha of 1 paints opaquely and
+// an alpha of 0 leaves the canvas unchanged.
+
+impl<T: Scalar> Canvas<T> {
+    // color * alpha + current * (1 - alpha)
+    pub fn blend_pixel(&mut self, x: isize, y: isize, color: Color<T>, alpha: f64) {
+        if x < 0 || y < 0 || x as usize >= self.width() || y as usize >= self.height() {
+            return;
+        }
+
+        let (x, y) = (x as usize, y as usize);
+        let alpha = alpha.clamp(0.0, 1.0);
+        let blended = color * T::from_f64(alpha) + self.pixel_at(x, y) * T::from_f64(1.0 - alpha);
+
+        self.write_pixel(x, y, blended);
+    }
+
+    // Bresenham's line, including both end points
+    pub fn draw_line(
+        &mut self,
+        from: (isize, isize),
+        to: (isize, isize),
+        color: Color<T>,
+        alpha: f64,
+    ) {
+        for (x, y) in Line::new(from, to).clipped(self.width(), self.height()) {
+            self.blend_pixel(x, y, color, alpha);
+        }
+    }
+
+    // Wu's anti-aliased line, where pixel centers lie on integer coordinates
+    pub fn draw_line_aa(&mut self, from: (f64, f64), to: (f64, f64), color: Color<T>, alpha: f64) {
+        let ((mut x0, mut y0), (mut x1, mut y1)) = (from, to);
+        if ![x0, y0, x1, y1].iter().all(|c| c.is_finite()) {
+            return;
+        }
+
+        let steep = (y1 - y0).abs() > (x1 - x0).abs();
+        if steep {
+            std::mem::swap(&mut x0, &mut y0);
+            std::mem::swap(&mut x1, &mut y1);
+        }
+        if x0 > x1 {
+            std::mem::swap(&mut x0, &mut x1);
+            std::mem::swap(&mut y0, &mut y1);
+        }
+
+        let extent = if steep { self.height() } else { self.width() } as f64;
+
+        let dx = x1 - x0;
+        let gradient = if dx == 0.0 { 1.0 } else { (y1 - y0) / dx };
+
+        let mut plot = |x: f64, y: f64, coverage: f64| {
+            let (x, y) = if steep { (y, x) } else { (x, y) };
+            self.blend_pixel(x as isize, y as isize, color, alpha * coverage);
+        };
+
+        // the end points are weighted by how much of their pixel they cover
+        let mut end_point = |x: f64, y: f64, x_coverage: f64| {
+            let x_end = x.round();
+            let y_end = y + gradient * (x_end - x);
+            let fraction = y_end - y_end.floor();
+
+            plot(x_end, y_end.floor(), (1.0 - fraction) * x_coverage);
+            plot(x_end, y_end.floor() + 1.0, fraction * x_coverage);
+            (x_end, y_end)
+        };
+
+        let (x_start, y_start) = end_point(x0, y0, 1.0 - (x0 + 0.5 - (x0 + 0.5).floor()));
+        let (x_end, _) = end_point(x1, y1, x1 + 0.5 - (x1 + 0.5).floor());
+
+        // only the columns over the canvas are walked
+        let mut x = (x_start + 1.0).max(0.0);
+        let x_end = x_end.min(extent);
+
+        while x < x_end {
+            let y = y_start + gradient * (x - x_start);
+            let fraction = y - y.floor();
+            plot(x, y.floor(), 1.0 - fraction);
+            plot(x, y.floor() + 1.0, fraction);
+
+            x += 1.0;
+        }
+    }
+
+    // midpoint circle outline, where the point of the first octant on row y
+    // is at the nearest integer to sqrt(r^2 - y^2); that lets each octant
+    // visit only the rows or columns that lie on the canvas
+    pub fn draw_circle(
+        &mut self,
+        center: (isize, isize),
+        radius: isize,
+        color: Color<T>,
+        alpha: f64,
+    ) {
+        if radius < 0 {
+            return;
+        }
+
+        let (cx, cy) = (center.0 as i128, center.1 as i128);
+        let (width, height) = (self.width() as i128, self.height() as i128);
+        let r = radius as u128;
+        let x_at = |y: u128| {
+            let d = r * r - y * y;
+            let root = d.isqrt();
+            root + u128::from(root * root + root < d)
+        };
+        // the octant ends where x drops below y
+        let steps = partition(r + 1, |y| x_at(y) < y) as i128;
+
+        for &(swap, sx, sy) in &[
+            (false, 1, 1),
+            (false, 1, -1),
+            (false, -1, 1),
+            (false, -1, -1),
+            (true, 1, 1),
+            (true, 1, -1),
+            (true, -1, 1),
+            (true, -1, -1),
+        ] {
+            // y moves along the rows when not swapped and the columns when
+            // swapped, so its range is limited by that extent
+            let (center, sign, extent) = if swap {
+                (cx, sx, width)
+            } else {
+                (cy, sy, height)
+            };
+            let (low, high) = if sign > 0 {
+                (-center, extent - 1 - center)
+            } else {
+                (center - extent + 1, center)
+            };
+
+            for y in low.max(0)..=high.min(steps - 1) {
+                let x = x_at(y as u128) as i128;
+
+                // the octants share points, which must not be blended twice
+                let (x_sign, y_sign) = if swap { (sy, sx) } else { (sx, sy) };
+                if (y == 0 && y_sign < 0) || (x == 0 && x_sign < 0) || (swap && x == y) {
+                    continue;
+                }
+
+                let (px, py) = if swap {
+                    (cx + sx * y, cy + sy * x)
+                } else {
+                    (cx + sx * x, cy + sy * y)
+                };
+                if (0..width).contains(&px) && (0..height).contains(&py) {
+                    self.blend_pixel(px as isize, py as isize, color, alpha);
+                }
+            }
+        }
+    }
+
+    pub fn fill_rect(
+        &mut self,
+        corner: (isize, isize),
+        width: usize,
+        height: usize,
+        color: Color<T>,
+        alpha: f64,
+    ) {
+        // the part of start..start + length that lies within 0..extent
+        let clip = |start: isize, length: usize, extent: usize| {
+            let end = (start as i128 + length as i128).clamp(0, extent as i128) as usize;
+            (start.max(0) as usize).min(end)..end
+        };
+
+        for y in clip(corner.1, height, self.height()) {
+            for x in clip(corner.0, width, self.width()) {
+                self.blend_pixel(x as isize, y as isize, color, alpha);
+            }
+        }
+    }
+
+    // connects consecutive points with Bresenham lines, blending each shared
+    // vertex once
+    pub fn draw_polyline(&mut self, points: &[(isize, isize)], color: Color<T>, alpha: f64) {
+        if let [only] = points {
+            self.blend_pixel(only.0, only.1, color, alpha);
+        }
+
+        for (i, segment) in points.windows(2).enumerate() {
+            let line = Line::new(segment[0], segment[1]);
+
+            for (x, y) in line.clipped(self.width(), self.height()) {
+                if i == 0 || (x, y) != segment[0] {
+                    self.blend_pixel(x, y, color, alpha);
+                }
+            }
+        }
+    }
+
+    // connects consecutive points with anti-aliased lines
+    pub fn draw_polyline_aa(&mut self, points: &[(f64, f64)], color: Color<T>, alpha: f64) {
+        for segment in points.windows(2) {
+            self.draw_line_aa(segment[0], segment[1], color, alpha);
+        }
+    }
+}
+
+// A Bresenham line, whose i-th pixel along the major axis is offset on the
+// minor axis by i * minor / major rounded half up. Working that out for any i
+// lets a line be clipped to the canvas without visiting the pixels outside of
+// it, however far away its end points are.
+struct Line {
+    start: (isize, isize),
+    x_major: bool,
+    major: u128,
+    minor: u128,
+    step_x: i128,
+    step_y: i128,
+}
+
+impl Line {
+    // the end points are ordered so that a line covers the same pixels
+    // whichever way it is drawn
+    fn new(from: (isize, isize), to: (isize, isize)) -> Self {
+        let (start, end) = if from <= to { (from, to) } else { (to, from) };
+        let dx = end.0 as i128 - start.0 as i128;
+        let dy = end.1 as i128 - start.1 as i128;
+        let (adx, ady) = (dx.unsigned_abs(), dy.unsigned_abs());
+
+        Line {
+            start,
+            x_major: adx >= ady,
+            major: adx.max(ady),
+            minor: adx.min(ady),
+            step_x: dx.signum(),
+            step_y: dy.signum(),
+        }
+    }
+
+    fn point(&self, i: u128) -> (i128, i128) {
+        // i and minor are at most 2^64 - 1, so their product fits
+        let product = i * self.minor;
+        let offset = product.checked_div(self.major).map_or(0, |quotient| {
+            quotient + u128::from(2 * (product % self.major) >= self.major)
+        }) as i128;
+        let (dx, dy) = if self.x_major {
+            (i as i128, offset)
+        } else {
+            (offset, i as i128)
+        };
+
+        (
+            self.start.0 as i128 + self.step_x * dx,
+            self.start.1 as i128 + self.step_y * dy,
+        )
+    }
+
+    // the pixels of the line that lie on a width x height canvas
+    fn clipped(self, width: usize, height: usize) -> impl Iterator<Item = (isize, isize)> {
+        let mut first = 0;
+        let mut last = self.major + 1;
+
+        // each coordinate moves monotonically along the line, so the steps
+        // inside the canvas on either axis form a range
+        for (axis, step, extent) in [(0, self.step_x, width), (1, self.step_y, height)] {
+            let coordinate = |i: u128| {
+                let (x, y) = self.point(i);
+                if axis == 0 {
+                    x
+                } else {
+                    y
+                }
+            };
+            let extent = extent as i128;
+            let enters = |c: i128| if step >= 0 { c >= 0 } else { c < extent };
+            let leaves = |c: i128| if step >= 0 { c >= extent } else { c < 0 };
+
+            first = first.max(partition(self.major + 1, |i| enters(coordinate(i))));
+            last = last.min(partition(self.major + 1, |i| leaves(coordinate(i))));
+        }
+
+        (first..last.max(first)).map(move |i| {
+            let (x, y) = self.point(i);
+            // inside the canvas, so within the range of isize
+            (x as isize, y as isize)
+        })
+    }
+}
+
+// the first i in 0..count for which the monotone predicate holds, or count
+fn partition(count: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    let (mut low, mut high) = (0, count);
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    low
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    DimensionMismatch {
+        expected: usize,
+        found: usize,
+    },
+    InvalidW {
+        expected: f64,
+        found: f64,
+    },
+    InvalidHex(String),
+    MalformedImage {
+        format: &'static str,
+        reason: String,
+    },
+    TruncatedImage {
+        format: &'static str,
+    },
+    MalformedDeflate(String),
+    UnknownImageFormat,
+    SizeMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    UnsupportedSize {
+        format: &'static str,
+        width: usize,
+        height: usize,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DimensionMismatch { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            Error::InvalidW { expected, found } => {
+                write!(f, "expected w = {}, found w = {}", expected, found)
+            }
+            Error::InvalidHex(hex) => write!(f, "invalid hex color {:?}", hex),
+            Error::MalformedImage { format, reason } => {
+                write!(f, "malformed {} image: {}", format, reason)
+            }
+            Error::TruncatedImage { format } => write!(f, "truncated {} image", format),
+            Error::MalformedDeflate(reason) => write!(f, "malformed deflate stream: {}", reason),
+            Error::UnknownImageFormat => write!(f, "unknown image format"),
+            Error::SizeMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} image, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Error::UnsupportedSize {
+                format,
+                width,
+                height,
+            } => write!(
+                f,
+                "a {}x{} image cannot be stored as {}",
+                width, height, format
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
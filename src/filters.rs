@@ -0,0 +1,246 @@
+use crate::canvas::Canvas;
+use crate::matrices::Matrix;
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+use std::f64::consts::PI;
+
+// Filters return a new canvas and leave the original untouched. Samples that
+// fall outside of the canvas take the value of the nearest edge pixel.
+
+const LANCZOS_LOBES: f64 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resampling {
+    Bilinear,
+    // windowed sinc with three lobes, widened when shrinking to avoid aliasing
+    Lanczos3,
+}
+
+impl<T: Scalar> Canvas<T> {
+    pub fn gaussian_blur(&self, sigma: f64) -> Self {
+        assert!(sigma.is_finite(), "cannot blur with a sigma of {}", sigma);
+        if sigma <= 0.0 {
+            return self.clone();
+        }
+
+        let weights = |length: usize| -> Vec<Vec<(usize, T)>> {
+            // taps further away than the canvas is long all land on an edge
+            let radius = (3.0 * sigma).ceil().min(length as f64) as isize;
+
+            (0..length)
+                .map(|i| {
+                    let mut taps: Vec<(usize, f64)> = Vec::new();
+
+                    for offset in -radius..=radius {
+                        let index = clamp_index(i as isize + offset, length);
+                        let weight = (-(offset * offset) as f64 / (2.0 * sigma * sigma)).exp();
+
+                        // taps clamped onto the same edge pixel are merged
+                        match taps.last_mut() {
+                            Some((last, sum)) if *last == index => *sum += weight,
+                            _ => taps.push((index, weight)),
+                        }
+                    }
+
+                    normalize(taps)
+                })
+                .collect()
+        };
+
+        self.convolve_rows(self.width(), &weights(self.width()))
+            .convolve_columns(self.height(), &weights(self.height()))
+    }
+
+    // keeps the part of each pixel's luminance that exceeds the threshold
+    pub fn bright_pass(&self, threshold: f64) -> Self {
+        self.map_pixels(|_, _, color| {
+            let luminance = color.luminance().to_f64();
+
+            if luminance <= threshold {
+                Color::black()
+            } else {
+                color * T::from_f64((luminance - threshold) / luminance)
+            }
+        })
+    }
+
+    // adds the blurred bright pass back onto the image
+    pub fn bloom(&self, threshold: f64, sigma: f64, intensity: f64) -> Self {
+        let glow = self.bright_pass(threshold).gaussian_blur(sigma);
+        let intensity = T::from_f64(intensity);
+
+        self.map_pixels(|x, y, color| color + glow.pixel_at(x, y) * intensity)
+    }
+
+    // darkens towards the corners by 1 - strength * d^2, where d is the
+    // distance from the center relative to the distance of the corners
+    pub fn vignette(&self, strength: f64) -> Self {
+        let (cx, cy) = (self.width() as f64 / 2.0, self.height() as f64 / 2.0);
+        let corner = cx * cx + cy * cy;
+
+        self.map_pixels(|x, y, color| {
+            let (dx, dy) = (x as f64 + 0.5 - cx, y as f64 + 0.5 - cy);
+            let distance = if corner > 0.0 {
+                (dx * dx + dy * dy) / corner
+            } else {
+                0.0
+            };
+
+            color * T::from_f64((1.0 - strength * distance).max(0.0))
+        })
+    }
+
+    // multiplies every color, taken as an (r, g, b) column, by the matrix
+    pub fn color_graded(&self, matrix: &Matrix<3, T>) -> Self {
+        self.map_pixels(|_, _, color| {
+            let rgb = [color.red(), color.green(), color.blue()];
+            let row = |i: usize| (0..3).map(|j| matrix[(i, j)] * rgb[j]).sum();
+
+            Color::new(row(0), row(1), row(2))
+        })
+    }
+
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        let fits = |start: usize, length: usize, extent: usize| {
+            start.checked_add(length).is_some_and(|end| end <= extent)
+        };
+        assert!(
+            fits(x, width, self.width()) && fits(y, height, self.height()),
+            "crop of {}x{} at ({}, {}) is outside of the {}x{} canvas",
+            width,
+            height,
+            x,
+            y,
+            self.width(),
+            self.height()
+        );
+
+        let pixels = (y..y + height)
+            .flat_map(|row| (x..x + width).map(move |column| (column, row)))
+            .map(|(column, row)| self.pixel_at(column, row))
+            .collect();
+
+        // exactly width * height pixels were taken
+        Canvas::from_pixels(width, height, pixels).unwrap()
+    }
+
+    pub fn resize(&self, width: usize, height: usize, resampling: Resampling) -> Self {
+        if width == 0 || height == 0 {
+            return Canvas::new(width, height);
+        }
+        assert!(
+            self.width() > 0 && self.height() > 0,
+            "cannot resize an empty canvas to {}x{}",
+            width,
+            height
+        );
+
+        self.convolve_rows(width, &resampling_weights(self.width(), width, resampling))
+            .convolve_columns(
+                height,
+                &resampling_weights(self.height(), height, resampling),
+            )
+    }
+
+    fn map_pixels(&self, f: impl Fn(usize, usize, Color<T>) -> Color<T>) -> Self {
+        let width = self.width().max(1);
+        let pixels = self
+            .pixels()
+            .iter()
+            .enumerate()
+            .map(|(i, &color)| f(i % width, i / width, color))
+            .collect();
+
+        // the dimensions are unchanged
+        Canvas::from_pixels(self.width(), self.height(), pixels).unwrap()
+    }
+
+    // weights[i] lists the source columns and weights of target column i
+    fn convolve_rows(&self, width: usize, weights: &[Vec<(usize, T)>]) -> Self {
+        let mut pixels = Vec::with_capacity(width * self.height());
+
+        for y in 0..self.height() {
+            pixels.extend(weights.iter().map(|taps| {
+                taps.iter().fold(Color::black(), |sum, &(x, weight)| {
+                    sum + self.pixel_at(x, y) * weight
+                })
+            }));
+        }
+
+        Canvas::from_pixels(width, self.height(), pixels).unwrap()
+    }
+
+    // weights[i] lists the source rows and weights of target row i
+    fn convolve_columns(&self, height: usize, weights: &[Vec<(usize, T)>]) -> Self {
+        let mut pixels = Vec::with_capacity(self.width() * height);
+
+        for taps in weights {
+            pixels.extend((0..self.width()).map(|x| {
+                taps.iter().fold(Color::black(), |sum, &(y, weight)| {
+                    sum + self.pixel_at(x, y) * weight
+                })
+            }));
+        }
+
+        Canvas::from_pixels(self.width(), height, pixels).unwrap()
+    }
+}
+
+fn resampling_weights<T: Scalar>(
+    source: usize,
+    target: usize,
+    resampling: Resampling,
+) -> Vec<Vec<(usize, T)>> {
+    let scale = source as f64 / target as f64;
+    let (support, stretch) = match resampling {
+        Resampling::Bilinear => (1.0, 1.0),
+        Resampling::Lanczos3 => (LANCZOS_LOBES, scale.max(1.0)),
+    };
+    let radius = support * stretch;
+
+    (0..target)
+        .map(|i| {
+            // pixel centers are at half-integer positions in both canvases
+            let center = (i as f64 + 0.5) * scale - 0.5;
+            let first = (center - radius).floor() as isize;
+            let last = (center + radius).ceil() as isize;
+
+            let taps = (first..=last)
+                .map(|j| {
+                    let x = (j as f64 - center) / stretch;
+                    let weight = match resampling {
+                        Resampling::Bilinear => (1.0 - x.abs()).max(0.0),
+                        Resampling::Lanczos3 => lanczos(x),
+                    };
+                    (clamp_index(j, source), weight)
+                })
+                .filter(|&(_, weight)| weight != 0.0)
+                .collect();
+
+            normalize(taps)
+        })
+        .collect()
+}
+
+fn lanczos(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else if x.abs() >= LANCZOS_LOBES {
+        0.0
+    } else {
+        let px = PI * x;
+        LANCZOS_LOBES * px.sin() * (px / LANCZOS_LOBES).sin() / (px * px)
+    }
+}
+
+fn normalize<T: Scalar>(taps: Vec<(usize, f64)>) -> Vec<(usize, T)> {
+    let sum: f64 = taps.iter().map(|&(_, weight)| weight).sum();
+
+    taps.into_iter()
+        .map(|(index, weight)| (index, T::from_f64(weight / sum)))
+        .collect()
+}
+
+fn clamp_index(index: isize, length: usize) -> usize {
+    index.clamp(0, length as isize - 1) as usize
+}
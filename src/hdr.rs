@@ -0,0 +1,335 @@
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+
+const FORMAT: &str = "Radiance HDR";
+// scanlines outside of this range cannot use the run-length encoding
+const MIN_RLE_WIDTH: usize = 8;
+const MAX_RLE_WIDTH: usize = 0x7fff;
+const MAX_RUN: usize = 127;
+const MAX_DUMP: usize = 128;
+// old-style repeats grow 256-fold, so a few bytes can claim any number of
+// pixels; larger images than 16384x16384 are refused instead of allocated
+const MAX_PIXELS: usize = 1 << 28;
+
+impl<T: Scalar> Canvas<T> {
+    // Radiance RGBE with run-length encoded scanlines; negative components
+    // are stored as zero
+    pub fn to_hdr(&self) -> Vec<u8> {
+        let mut hdr = format!(
+            "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {} +X {}\n",
+            self.height(),
+            self.width()
+        )
+        .into_bytes();
+
+        for row in self.pixels().chunks(self.width().max(1)) {
+            let rgbe: Vec<[u8; 4]> = row.iter().map(to_rgbe).collect();
+
+            if !(MIN_RLE_WIDTH..=MAX_RLE_WIDTH).contains(&row.len()) {
+                hdr.extend(rgbe.iter().flatten());
+                continue;
+            }
+
+            hdr.extend_from_slice(&[2, 2, (row.len() >> 8) as u8, row.len() as u8]);
+
+            // each component is encoded separately
+            for component in 0..4 {
+                let values: Vec<u8> = rgbe.iter().map(|pixel| pixel[component]).collect();
+                encode_runs(&mut hdr, &values);
+            }
+        }
+
+        hdr
+    }
+
+    // reads flat, old-style and new-style run-length encoded RGBE images in
+    // the standard -Y +X orientation
+    pub fn from_hdr(bytes: &[u8]) -> Result<Self> {
+        let mut position = 0;
+
+        let magic = line(bytes, &mut position)?;
+        if !magic.starts_with(b"#?") {
+            return Err(malformed("missing #? signature".to_string()));
+        }
+
+        loop {
+            let header = line(bytes, &mut position)?;
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some(format) = header.strip_prefix(b"FORMAT=") {
+                if format != b"32-bit_rle_rgbe" {
+                    return Err(malformed(format!(
+                        "unsupported pixel format {:?}",
+                        String::from_utf8_lossy(format)
+                    )));
+                }
+            }
+        }
+
+        let resolution = line(bytes, &mut position)?;
+        let (width, height) = parse_resolution(resolution)?;
+
+        if width == 0 || height == 0 {
+            return Ok(Canvas::new(width, height));
+        }
+
+        // every scanline takes at least this many bytes however it is encoded,
+        // so larger images cannot fit in what is left of the file
+        let remaining = bytes.len() - position;
+        if height
+            .checked_mul(min_scanline_bytes(width))
+            .is_none_or(|needed| needed > remaining)
+        {
+            return Err(Error::TruncatedImage { format: FORMAT });
+        }
+        if width
+            .checked_mul(height)
+            .is_none_or(|count| count > MAX_PIXELS)
+        {
+            return Err(malformed(format!("{}x{} is too large", width, height)));
+        }
+
+        let mut pixels = Vec::new();
+        let mut scanline = vec![[0u8; 4]; width];
+
+        for _ in 0..height {
+            read_scanline(bytes, &mut position, &mut scanline)?;
+            pixels.extend(scanline.iter().map(from_rgbe));
+        }
+
+        Canvas::from_pixels(width, height, pixels)
+    }
+}
+
+fn to_rgbe<T: Scalar>(color: &Color<T>) -> [u8; 4] {
+    let (r, g, b) = (
+        color.red().to_f64().max(0.0),
+        color.green().to_f64().max(0.0),
+        color.blue().to_f64().max(0.0),
+    );
+    let v = r.max(g).max(b);
+
+    if v.is_nan() || v < 1e-32 {
+        return [0; 4];
+    }
+    // the largest exponent byte is 255, so values of 2^127 and above saturate
+    if v >= 2f64.powi(127) {
+        return [255; 4];
+    }
+
+    // v = m * 2^e with m in [0.5, 1)
+    let mut e = v.log2().floor() as i32 + 1;
+    let mut m = v / 2f64.powi(e);
+    if m >= 1.0 {
+        m /= 2.0;
+        e += 1;
+    } else if m < 0.5 {
+        m *= 2.0;
+        e -= 1;
+    }
+
+    let scale = m * 256.0 / v;
+    [
+        (r * scale) as u8,
+        (g * scale) as u8,
+        (b * scale) as u8,
+        (e + 128) as u8,
+    ]
+}
+
+fn from_rgbe<T: Scalar>(rgbe: &[u8; 4]) -> Color<T> {
+    if rgbe[3] == 0 {
+        return Color::black();
+    }
+
+    // encoding truncates, so like Radiance's colr_color this decodes to the
+    // middle of each step; zero mantissas stay zero
+    let f = 2f64.powi(i32::from(rgbe[3]) - (128 + 8));
+    let decode = |mantissa: u8| match mantissa {
+        0 => T::ZERO,
+        _ => T::from_f64((f64::from(mantissa) + 0.5) * f),
+    };
+
+    Color::new(decode(rgbe[0]), decode(rgbe[1]), decode(rgbe[2]))
+}
+
+// the smallest encoding of a scanline is its first pixel followed by old-style
+// repeats, the k-th of which covers up to 255 << 8k pixels
+fn min_scanline_bytes(width: usize) -> usize {
+    let mut bytes = 4;
+    let mut covered = 1usize;
+    let mut longest_repeat = 255usize;
+
+    while covered < width {
+        bytes += 4;
+        covered = covered.saturating_add(longest_repeat);
+        longest_repeat = longest_repeat.saturating_mul(256);
+    }
+
+    bytes
+}
+
+// a run is a count above 128 followed by the byte to repeat, a dump is a count
+// of up to 128 followed by that many literal bytes
+fn encode_runs(out: &mut Vec<u8>, values: &[u8]) {
+    let mut start = 0;
+
+    while start < values.len() {
+        let run = values[start..]
+            .iter()
+            .take(MAX_RUN)
+            .take_while(|&&value| value == values[start])
+            .count();
+
+        if run > 2 {
+            out.extend_from_slice(&[128 + run as u8, values[start]]);
+            start += run;
+            continue;
+        }
+
+        // dump until the next run of at least three equal bytes
+        let mut end = start + 1;
+        while end < values.len() && end - start < MAX_DUMP {
+            if end + 2 < values.len()
+                && values[end] == values[end + 1]
+                && values[end] == values[end + 2]
+            {
+                break;
+            }
+            end += 1;
+        }
+
+        out.push((end - start) as u8);
+        out.extend_from_slice(&values[start..end]);
+        start = end;
+    }
+}
+
+fn read_scanline(bytes: &[u8], position: &mut usize, scanline: &mut [[u8; 4]]) -> Result<()> {
+    let width = scanline.len();
+    let head = bytes.get(*position..*position + 4);
+
+    match head {
+        Some(&[2, 2, high, low])
+            if (MIN_RLE_WIDTH..=MAX_RLE_WIDTH).contains(&width) && high & 0x80 == 0 =>
+        {
+            if usize::from(high) << 8 | usize::from(low) != width {
+                return Err(malformed("scanline width mismatch".to_string()));
+            }
+            *position += 4;
+
+            for component in 0..4 {
+                let mut x = 0;
+                while x < width {
+                    let count = usize::from(byte(bytes, position)?);
+
+                    if count > 128 {
+                        let count = count - 128;
+                        let value = byte(bytes, position)?;
+                        if x + count > width {
+                            return Err(malformed("run overflows the scanline".to_string()));
+                        }
+                        for pixel in &mut scanline[x..x + count] {
+                            pixel[component] = value;
+                        }
+                        x += count;
+                    } else {
+                        if count == 0 || x + count > width {
+                            return Err(malformed("invalid dump length".to_string()));
+                        }
+                        for pixel in &mut scanline[x..x + count] {
+                            pixel[component] = byte(bytes, position)?;
+                        }
+                        x += count;
+                    }
+                }
+            }
+        }
+        _ => {
+            // flat pixels, where (1, 1, 1, n) repeats the previous pixel
+            // n << shift times, the shift growing with consecutive repeats
+            let mut x = 0;
+            let mut shift = 0;
+
+            while x < width {
+                let pixel = bytes
+                    .get(*position..*position + 4)
+                    .ok_or(Error::TruncatedImage { format: FORMAT })?;
+                *position += 4;
+
+                if pixel[..3] == [1, 1, 1] {
+                    if x == 0 {
+                        return Err(malformed("repeat at the start of a scanline".to_string()));
+                    }
+                    let count = usize::from(pixel[3])
+                        .checked_shl(shift)
+                        .filter(|&count| x + count <= width)
+                        .ok_or_else(|| malformed("run overflows the scanline".to_string()))?;
+                    let previous = scanline[x - 1];
+                    for value in &mut scanline[x..x + count] {
+                        *value = previous;
+                    }
+                    x += count;
+                    shift += 8;
+                } else {
+                    scanline[x] = [pixel[0], pixel[1], pixel[2], pixel[3]];
+                    x += 1;
+                    shift = 0;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_resolution(line: &[u8]) -> Result<(usize, usize)> {
+    let invalid = || {
+        malformed(format!(
+            "unsupported resolution {:?}",
+            String::from_utf8_lossy(line)
+        ))
+    };
+
+    let text = std::str::from_utf8(line).map_err(|_| invalid())?;
+    let fields: Vec<&str> = text.split_whitespace().collect();
+
+    match fields[..] {
+        ["-Y", height, "+X", width] => Ok((
+            width.parse().map_err(|_| invalid())?,
+            height.parse().map_err(|_| invalid())?,
+        )),
+        _ => Err(invalid()),
+    }
+}
+
+fn line<'a>(bytes: &'a [u8], position: &mut usize) -> Result<&'a [u8]> {
+    let rest = &bytes[*position..];
+    let end = rest
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .ok_or(Error::TruncatedImage { format: FORMAT })?;
+
+    *position += end + 1;
+    Ok(&rest[..end])
+}
+
+fn byte(bytes: &[u8], position: &mut usize) -> Result<u8> {
+    let byte = *bytes
+        .get(*position)
+        .ok_or(Error::TruncatedImage { format: FORMAT })?;
+
+    *position += 1;
+    Ok(byte)
+}
+
+fn malformed(reason: String) -> Error {
+    Error::MalformedImage {
+        format: FORMAT,
+        reason,
+    }
+}
@@ -0,0 +1,63 @@
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Pfm,
+    Hdr,
+}
+
+impl ImageFormat {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            "pfm" => Some(ImageFormat::Pfm),
+            "hdr" => Some(ImageFormat::Hdr),
+            _ => None,
+        }
+    }
+
+    // guesses the format from the magic number at the start of the file
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[137, b'P', b'N', b'G']) {
+            Some(ImageFormat::Png)
+        } else if bytes.starts_with(b"P3") || bytes.starts_with(b"P6") {
+            Some(ImageFormat::Ppm)
+        } else if bytes.starts_with(b"PF") || bytes.starts_with(b"Pf") {
+            Some(ImageFormat::Pfm)
+        } else if bytes.starts_with(b"#?") {
+            Some(ImageFormat::Hdr)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Scalar> Canvas<T> {
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        match ImageFormat::detect(bytes) {
+            Some(ImageFormat::Ppm) => Canvas::from_ppm(bytes),
+            Some(ImageFormat::Png) => Canvas::from_png(bytes),
+            Some(ImageFormat::Pfm) => Canvas::from_pfm(bytes),
+            Some(ImageFormat::Hdr) => Canvas::from_hdr(bytes),
+            None => Err(Error::UnknownImageFormat),
+        }
+    }
+
+    // fails when the format cannot hold a canvas of this size
+    pub fn encode(&self, format: ImageFormat) -> Result<Vec<u8>> {
+        match format {
+            ImageFormat::Ppm => Ok(self.to_ppm().into_bytes()),
+            ImageFormat::Png => self.to_png(),
+            ImageFormat::Pfm => Ok(self.to_pfm()),
+            ImageFormat::Hdr => Ok(self.to_hdr()),
+        }
+    }
+}
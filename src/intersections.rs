@@ -0,0 +1,187 @@
+use crate::rays::Ray;
+use crate::scalar::Scalar;
+use crate::tuples::{dot, Point, Vector};
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+use std::ops::Index;
+
+// Anything a ray can hit must report the surface normal at a world space point
+// on it, so that the shading data can be precomputed.
+pub trait Normal<T = f64> {
+    fn normal_at(&self, world_point: &Point<T>) -> Vector<T>;
+}
+
+#[derive(Debug)]
+pub struct Intersection<'a, O: ?Sized, T = f64> {
+    pub t: T,
+    pub object: &'a O,
+}
+
+// the intersections along a ray, sorted by increasing t
+#[derive(Debug)]
+pub struct Intersections<'a, O: ?Sized, T = f64> {
+    values: Vec<Intersection<'a, O, T>>,
+}
+
+#[derive(Debug)]
+pub struct Computations<'a, O: ?Sized, T = f64> {
+    pub t: T,
+    pub object: &'a O,
+    pub point: Point<T>,
+    pub eyev: Vector<T>,
+    pub normalv: Vector<T>,
+    // true when the ray starts inside the object, in which case the normal is
+    // flipped to face the eye
+    pub inside: bool,
+    // the point nudged along the normal, to keep shadow rays from hitting the
+    // surface they start on
+    pub over_point: Point<T>,
+    // the point nudged against the normal, where refracted rays start
+    pub under_point: Point<T>,
+}
+
+impl<'a, O: ?Sized, T: Scalar> Intersection<'a, O, T> {
+    pub fn new(t: T, object: &'a O) -> Self {
+        Intersection { t, object }
+    }
+
+    pub fn prepare_computations(&self, ray: &Ray<T>) -> Computations<'a, O, T>
+    where
+        O: Normal<T>,
+    {
+        let point = ray.position(self.t);
+        let eyev = -ray.direction;
+        let mut normalv = self.object.normal_at(&point);
+
+        let inside = dot(&normalv, &eyev) < T::ZERO;
+        if inside {
+            normalv = -normalv;
+        }
+
+        let offset = normalv * T::EPSILON;
+
+        Computations {
+            t: self.t,
+            object: self.object,
+            point,
+            eyev,
+            normalv,
+            inside,
+            over_point: point + offset,
+            under_point: point - offset,
+        }
+    }
+}
+
+impl<O: ?Sized, T: Copy> Clone for Intersection<'_, O, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O: ?Sized, T: Copy> Copy for Intersection<'_, O, T> {}
+
+// two intersections are equal when they are at the same t on the same object
+impl<O: ?Sized, T: Scalar> PartialEq for Intersection<'_, O, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t && std::ptr::addr_eq(self.object, other.object)
+    }
+}
+
+impl<'a, O: ?Sized, T: Scalar> Intersections<'a, O, T> {
+    pub fn new(mut values: Vec<Intersection<'a, O, T>>) -> Self {
+        values.sort_by(|a, b| compare(a.t, b.t));
+
+        Intersections { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Intersection<'a, O, T>> {
+        self.values.iter()
+    }
+
+    // inserts after any intersections with the same t
+    pub fn push(&mut self, intersection: Intersection<'a, O, T>) {
+        let index = self
+            .values
+            .partition_point(|other| compare(other.t, intersection.t) != Ordering::Greater);
+
+        self.values.insert(index, intersection);
+    }
+
+    pub fn extend(&mut self, other: Intersections<'a, O, T>) {
+        self.values.extend(other.values);
+        self.values.sort_by(|a, b| compare(a.t, b.t));
+    }
+
+    // the visible intersection, which is the one with the lowest non-negative
+    // t; intersections behind the ray's origin are never hits
+    pub fn hit(&self) -> Option<&Intersection<'a, O, T>> {
+        self.values.iter().find(|i| i.t >= T::ZERO)
+    }
+}
+
+impl<O: ?Sized, T> Default for Intersections<'_, O, T> {
+    fn default() -> Self {
+        Intersections { values: Vec::new() }
+    }
+}
+
+impl<O: ?Sized, T> Clone for Intersections<'_, O, T>
+where
+    T: Copy,
+{
+    fn clone(&self) -> Self {
+        Intersections {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<'a, O: ?Sized, T> Index<usize> for Intersections<'a, O, T> {
+    type Output = Intersection<'a, O, T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl<'a, O: ?Sized, T: Scalar> FromIterator<Intersection<'a, O, T>> for Intersections<'a, O, T> {
+    fn from_iter<I: IntoIterator<Item = Intersection<'a, O, T>>>(iter: I) -> Self {
+        Intersections::new(iter.into_iter().collect())
+    }
+}
+
+impl<'a, O: ?Sized, T> IntoIterator for Intersections<'a, O, T> {
+    type Item = Intersection<'a, O, T>;
+    type IntoIter = std::vec::IntoIter<Intersection<'a, O, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a, 'b, O: ?Sized, T> IntoIterator for &'b Intersections<'a, O, T> {
+    type Item = &'b Intersection<'a, O, T>;
+    type IntoIter = std::slice::Iter<'b, Intersection<'a, O, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+// NaN sorts last, so it can never shadow a real hit
+fn compare<T: Scalar>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| {
+        let a_nan = a.partial_cmp(&a).is_none();
+        let b_nan = b.partial_cmp(&b).is_none();
+        a_nan.cmp(&b_nan)
+    })
+}
@@ -1,4 +1,30 @@
+pub mod approx;
+pub mod canvas;
+pub mod compare;
+pub mod deflate;
+pub mod dithering;
+pub mod draw;
+pub mod error;
+pub mod filters;
+pub mod hdr;
+pub mod image;
+pub mod intersections;
+pub mod materials;
+pub mod matrices;
+pub mod pfm;
+pub mod planes;
+pub mod png;
+pub mod ppm;
 pub mod projectile;
+pub mod quaternions;
+pub mod rays;
+pub mod scalar;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod shapes;
+pub mod spheres;
+pub mod tone_mapping;
+pub mod transformations;
 pub mod tuples;
 
 #[cfg(test)]
@@ -0,0 +1,40 @@
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+
+// the surface attributes of the Phong reflection model
+#[derive(Debug, Clone, Copy)]
+pub struct Material<T = f64> {
+    pub color: Color<T>,
+    pub ambient: T,
+    pub diffuse: T,
+    pub specular: T,
+    pub shininess: T,
+}
+
+impl<T: Scalar> Material<T> {
+    pub fn new() -> Self {
+        Material {
+            color: Color::white(),
+            ambient: T::from_f64(0.1),
+            diffuse: T::from_f64(0.9),
+            specular: T::from_f64(0.9),
+            shininess: T::from_f64(200.0),
+        }
+    }
+}
+
+impl<T: Scalar> Default for Material<T> {
+    fn default() -> Self {
+        Material::new()
+    }
+}
+
+impl<T: Scalar> PartialEq for Material<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.color == other.color
+            && self.ambient.approx_eq(&other.ambient)
+            && self.diffuse.approx_eq(&other.diffuse)
+            && self.specular.approx_eq(&other.specular)
+            && self.shininess.approx_eq(&other.shininess)
+    }
+}
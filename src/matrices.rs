@@ -0,0 +1,215 @@
+use crate::approx::ApproxEq;
+use crate::scalar::Scalar;
+use crate::tuples::{Point, Tuple, Value, Vector};
+use duplicate::duplicate;
+use std::ops::{Index, IndexMut, Mul};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix<const N: usize, T = f64> {
+    values: [[T; N]; N],
+}
+
+impl<const N: usize, T: Scalar> Matrix<N, T> {
+    pub fn new(values: [[T; N]; N]) -> Self {
+        Matrix { values }
+    }
+
+    pub fn identity() -> Self {
+        let mut values = [[T::ZERO; N]; N];
+        for (i, row) in values.iter_mut().enumerate() {
+            row[i] = T::ONE;
+        }
+        Matrix { values }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut values = [[T::ZERO; N]; N];
+        for (i, row) in values.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.values[j][i];
+            }
+        }
+        Matrix { values }
+    }
+
+    pub fn rows(&self) -> &[[T; N]; N] {
+        &self.values
+    }
+}
+
+impl<T: Scalar> Matrix<1, T> {
+    pub fn determinant(&self) -> T {
+        self.values[0][0]
+    }
+}
+
+#[duplicate(
+   size sub_size;
+   [ 2 ] [ 1 ];
+   [ 3 ] [ 2 ];
+   [ 4 ] [ 3 ];
+)]
+impl<T: Scalar> Matrix<size, T> {
+    pub fn submatrix(&self, row: usize, column: usize) -> Matrix<sub_size, T> {
+        let mut values = [[T::ZERO; sub_size]; sub_size];
+        let rows = self.values.iter().enumerate().filter(|&(i, _)| i != row);
+
+        for (sub_row, (_, self_row)) in values.iter_mut().zip(rows) {
+            let columns = self_row.iter().enumerate().filter(|&(j, _)| j != column);
+
+            for (value, (_, &self_value)) in sub_row.iter_mut().zip(columns) {
+                *value = self_value;
+            }
+        }
+
+        Matrix { values }
+    }
+
+    pub fn minor(&self, row: usize, column: usize) -> T {
+        self.submatrix(row, column).determinant()
+    }
+
+    pub fn cofactor(&self, row: usize, column: usize) -> T {
+        let minor = self.minor(row, column);
+
+        if (row + column) % 2 == 0 {
+            minor
+        } else {
+            -minor
+        }
+    }
+
+    pub fn determinant(&self) -> T {
+        (0..size)
+            .map(|column| self.values[0][column] * self.cofactor(0, column))
+            .sum()
+    }
+
+    pub fn is_invertible(&self) -> bool {
+        self.determinant() != T::ZERO
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == T::ZERO {
+            return None;
+        }
+
+        let mut values = [[T::ZERO; size]; size];
+        for (row, inverse_row) in values.iter_mut().enumerate() {
+            for (column, value) in inverse_row.iter_mut().enumerate() {
+                // transposed: the cofactor of (column, row) lands on (row, column)
+                *value = self.cofactor(column, row) / determinant;
+            }
+        }
+
+        Some(Matrix { values })
+    }
+}
+
+impl<const N: usize, T: Scalar> Index<(usize, usize)> for Matrix<N, T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        &self.values[row][column]
+    }
+}
+
+impl<const N: usize, T: Scalar> IndexMut<(usize, usize)> for Matrix<N, T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        &mut self.values[row][column]
+    }
+}
+
+impl<const N: usize, T: Scalar> ApproxEq for Matrix<N, T> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.values.abs_diff_eq(&other.values, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.values
+            .relative_eq(&other.values, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.values.ulps_eq(&other.values, epsilon, max_ulps)
+    }
+}
+
+impl<const N: usize, T: Scalar> PartialEq for Matrix<N, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
+}
+
+#[duplicate(
+   Lhs Rhs;
+   [ Matrix<N, T> ] [ Matrix<N, T> ];
+   [ Matrix<N, T> ] [ &Matrix<N, T> ];
+   [ &Matrix<N, T> ] [ Matrix<N, T> ];
+   [ &Matrix<N, T> ] [ &Matrix<N, T> ];
+)]
+impl<const N: usize, T: Scalar> Mul<Rhs> for Lhs {
+    type Output = Matrix<N, T>;
+
+    fn mul(self, rhs: Rhs) -> Self::Output {
+        let mut values = [[T::ZERO; N]; N];
+        for (row, product_row) in values.iter_mut().enumerate() {
+            for (column, value) in product_row.iter_mut().enumerate() {
+                *value = (0..N)
+                    .map(|k| self.values[row][k] * rhs.values[k][column])
+                    .sum();
+            }
+        }
+
+        Matrix { values }
+    }
+}
+
+#[duplicate(
+   Lhs Rhs Output_type construct;
+   #[
+   Rhs_nested Output_nested construct_nested;
+    [ Tuple<T> ] [ Tuple<T> ] [ Tuple::new(r[0], r[1], r[2], r[3]) ];
+    // the w component of points and vectors is fixed, so only x, y and z
+    // are taken from the product
+    [ Point<T> ] [ Point<T> ] [ Point::new(r[0], r[1], r[2]) ];
+    [ Vector<T> ] [ Vector<T> ] [ Vector::new(r[0], r[1], r[2]) ];
+   ][
+    [ Matrix<4, T> ] [ Rhs_nested ] [ Output_nested ] [ construct_nested ];
+    [ Matrix<4, T> ] [ &Rhs_nested ] [ Output_nested ] [ construct_nested ];
+    [ &Matrix<4, T> ] [ Rhs_nested ] [ Output_nested ] [ construct_nested ];
+    [ &Matrix<4, T> ] [ &Rhs_nested ] [ Output_nested ] [ construct_nested ];
+   ]
+)]
+impl<T: Scalar> Mul<Rhs> for Lhs {
+    type Output = Output_type;
+
+    fn mul(self, rhs: Rhs) -> Self::Output {
+        let r = multiply(&self.values, rhs.value());
+        construct
+    }
+}
+
+fn multiply<T: Scalar>(values: &[[T; 4]; 4], tuple: &[T; 4]) -> [T; 4] {
+    let mut result = [T::ZERO; 4];
+    for (value, row) in result.iter_mut().zip(values.iter()) {
+        *value = row.iter().zip(tuple.iter()).map(|(&m, &t)| m * t).sum();
+    }
+    result
+}
@@ -0,0 +1,101 @@
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::ppm::Reader;
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+
+const FORMAT: &str = "PFM";
+
+impl<T: Scalar> Canvas<T> {
+    // color (PF) Portable Float Map with little-endian 32-bit samples; the
+    // values are stored unclamped
+    pub fn to_pfm(&self) -> Vec<u8> {
+        let mut pfm = format!("PF\n{} {}\n-1.0\n", self.width(), self.height()).into_bytes();
+        pfm.reserve(self.width() * self.height() * 12);
+
+        // scanlines are stored from the bottom to the top
+        for row in self.pixels().chunks(self.width().max(1)).rev() {
+            for pixel in row {
+                for value in [pixel.red(), pixel.green(), pixel.blue()] {
+                    pfm.extend_from_slice(&(value.to_f64() as f32).to_le_bytes());
+                }
+            }
+        }
+
+        pfm
+    }
+
+    // reads color (PF) and grayscale (Pf) maps of either byte order; the
+    // magnitude of the scale factor is not applied
+    pub fn from_pfm(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes, FORMAT);
+
+        let channels = match reader.token()? {
+            b"PF" => 3,
+            b"Pf" => 1,
+            magic => {
+                return Err(malformed(format!(
+                    "unknown magic number {:?}",
+                    String::from_utf8_lossy(magic)
+                )))
+            }
+        };
+        let width = reader.number("width")?;
+        let height = reader.number("height")?;
+
+        let token = reader.token()?;
+        let scale = std::str::from_utf8(token)
+            .ok()
+            .and_then(|scale| scale.parse::<f64>().ok())
+            .filter(|scale| scale.is_finite() && *scale != 0.0)
+            .ok_or_else(|| {
+                malformed(format!(
+                    "expected scale, found {:?}",
+                    String::from_utf8_lossy(token)
+                ))
+            })?;
+        let little_endian = scale < 0.0;
+
+        reader.single_whitespace()?;
+
+        let size = width
+            .checked_mul(height)
+            .and_then(|pixels| pixels.checked_mul(channels * 4))
+            .ok_or_else(|| malformed(format!("{}x{} is too large", width, height)))?;
+
+        let data = reader.remaining();
+        if data.len() < size {
+            return Err(Error::TruncatedImage { format: FORMAT });
+        }
+
+        let values: Vec<T> = data[..size]
+            .chunks(4)
+            .map(|sample| {
+                let sample = [sample[0], sample[1], sample[2], sample[3]];
+                let value = if little_endian {
+                    f32::from_le_bytes(sample)
+                } else {
+                    f32::from_be_bytes(sample)
+                };
+                T::from_f64(f64::from(value))
+            })
+            .collect();
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in values.chunks(width.max(1) * channels).rev() {
+            pixels.extend(row.chunks(channels).map(|samples| match samples {
+                [r, g, b] => Color::new(*r, *g, *b),
+                _ => Color::new(samples[0], samples[0], samples[0]),
+            }));
+        }
+
+        Canvas::from_pixels(width, height, pixels)
+    }
+}
+
+fn malformed(reason: String) -> Error {
+    Error::MalformedImage {
+        format: FORMAT,
+        reason,
+    }
+}
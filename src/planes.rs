@@ -0,0 +1,23 @@
+use crate::rays::Ray;
+use crate::scalar::Scalar;
+use crate::shapes::Shape;
+use crate::tuples::{Point, Vector};
+
+// the infinite xz plane through the origin of its object space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Plane;
+
+impl<T: Scalar> Shape<T> for Plane {
+    // rays parallel to the plane, including those lying in it, never hit it
+    fn local_intersect(&self, ray: &Ray<T>) -> Vec<T> {
+        if ray.direction.y().abs() < T::EPSILON {
+            return Vec::new();
+        }
+
+        vec![-ray.origin.y() / ray.direction.y()]
+    }
+
+    fn local_normal_at(&self, _point: &Point<T>) -> Vector<T> {
+        Vector::new(T::ZERO, T::ONE, T::ZERO)
+    }
+}
@@ -0,0 +1,464 @@
+use crate::canvas::Canvas;
+use crate::deflate::{zlib_compress, zlib_decompress};
+use crate::dithering::Dither;
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
+use crate::tone_mapping::ExportOptions;
+use crate::tuples::Color;
+
+const FORMAT: &str = "PNG";
+const SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+const CRC_TABLE: [u32; 256] = crc_table();
+// the largest width or height an IHDR chunk may hold
+const MAX_DIMENSION: usize = (1 << 31) - 1;
+
+// (x offset, y offset, x step, y step) of the seven Adam7 passes
+const ADAM7: [(usize, usize, usize, usize); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+impl<T: Scalar> Canvas<T> {
+    // 8-bit RGB, non-interlaced, with the filter of each scanline chosen by
+    // the minimum sum of absolute differences heuristic; PNG has no empty
+    // images, so canvases without pixels are an error
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        self.check_png_size()?;
+        Ok(encode(
+            self.width(),
+            self.height(),
+            &self.to_rgb8(Dither::None),
+        ))
+    }
+
+    // tone maps and dithers according to the options before quantizing
+    pub fn to_png_with(&self, options: &ExportOptions) -> Result<Vec<u8>> {
+        self.check_png_size()?;
+        let rgb = self.tone_mapped(options).to_rgb8(options.dither);
+        Ok(encode(self.width(), self.height(), &rgb))
+    }
+
+    fn check_png_size(&self) -> Result<()> {
+        let valid = |dimension: usize| (1..=MAX_DIMENSION).contains(&dimension);
+
+        if valid(self.width()) && valid(self.height()) {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedSize {
+                format: FORMAT,
+                width: self.width(),
+                height: self.height(),
+            })
+        }
+    }
+
+    // reads every standard color type and bit depth, interlaced or not;
+    // alpha channels and ancillary chunks are ignored
+    pub fn from_png(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < SIGNATURE.len() {
+            return Err(Error::TruncatedImage { format: FORMAT });
+        }
+        if bytes[..SIGNATURE.len()] != SIGNATURE {
+            return Err(malformed("invalid signature".to_string()));
+        }
+
+        let mut position = SIGNATURE.len();
+        let mut header = None;
+        let mut palette = Vec::new();
+        let mut data = Vec::new();
+
+        loop {
+            let (kind, chunk) = read_chunk(bytes, &mut position)?;
+
+            if header.is_none() && &kind != b"IHDR" {
+                return Err(malformed("the first chunk is not IHDR".to_string()));
+            }
+
+            match &kind {
+                b"IHDR" => header = Some(Header::parse(chunk)?),
+                b"PLTE" => {
+                    if chunk.len() % 3 != 0 || chunk.len() > 256 * 3 {
+                        return Err(malformed(format!(
+                            "palette of {} bytes is invalid",
+                            chunk.len()
+                        )));
+                    }
+                    palette = chunk
+                        .chunks(3)
+                        .map(|rgb| [rgb[0], rgb[1], rgb[2]])
+                        .collect();
+                }
+                b"IDAT" => data.extend_from_slice(chunk),
+                b"IEND" => break,
+                // bit 5 of the first byte is clear for chunks a decoder must
+                // understand
+                _ if kind[0] & 0x20 == 0 => {
+                    return Err(malformed(format!(
+                        "unknown critical chunk {:?}",
+                        String::from_utf8_lossy(&kind)
+                    )))
+                }
+                _ => {}
+            }
+        }
+
+        // IHDR is required to be the first chunk, which was checked above
+        let header = header.unwrap();
+
+        if header.color_type == 3 && palette.is_empty() {
+            return Err(malformed("missing palette".to_string()));
+        }
+
+        let raw = zlib_decompress(&data)?;
+        let passes = header.passes();
+
+        let expected = passes
+            .iter()
+            .try_fold(0usize, |total, &(_, _, _, _, width, height)| {
+                header
+                    .row_bytes(width)
+                    .and_then(|row| row.checked_add(1))
+                    .and_then(|row| row.checked_mul(height))
+                    .and_then(|pass| total.checked_add(pass))
+            })
+            .ok_or_else(|| malformed(format!("{}x{} is too large", header.width, header.height)))?;
+
+        if raw.len() < expected {
+            return Err(Error::TruncatedImage { format: FORMAT });
+        }
+
+        let mut pixels = vec![Color::black(); header.width * header.height];
+        let mut rest = &raw[..];
+
+        for (x0, y0, dx, dy, width, height) in passes {
+            // row_bytes was checked while computing the expected size
+            let stride = header.row_bytes(width).unwrap();
+            let mut previous = vec![0; stride];
+
+            for j in 0..height {
+                let (row, tail) = rest.split_at(stride + 1);
+                rest = tail;
+
+                let current = unfilter(row[0], &row[1..], &previous, header.filter_step())?;
+
+                for i in 0..width {
+                    let color = header.color(&current, i, &palette)?;
+                    pixels[(y0 + j * dy) * header.width + x0 + i * dx] = color;
+                }
+
+                previous = current;
+            }
+        }
+
+        Canvas::from_pixels(header.width, header.height, pixels)
+    }
+}
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !update_crc(!0, bytes)
+}
+
+fn encode(width: usize, height: usize, rgb: &[[u8; 3]]) -> Vec<u8> {
+    let mut png = SIGNATURE.to_vec();
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // bit depth, color type (RGB), compression, filter, interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+
+    let stride = width * 3;
+    let mut raw = Vec::with_capacity((stride + 1) * height);
+    let mut previous = vec![0; stride];
+
+    for row in rgb.chunks(width.max(1)) {
+        let current: Vec<u8> = row.iter().flatten().copied().collect();
+
+        let (kind, filtered) = (0..5)
+            .map(|kind| (kind, filter(kind, &current, &previous, 3)))
+            .min_by_key(|(_, filtered)| {
+                filtered
+                    .iter()
+                    .map(|&byte| u64::from((byte as i8).unsigned_abs()))
+                    .sum::<u64>()
+            })
+            .unwrap();
+
+        raw.push(kind);
+        raw.extend_from_slice(&filtered);
+        previous = current;
+    }
+
+    write_chunk(&mut png, b"IDAT", &zlib_compress(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+
+    png
+}
+
+struct Header {
+    width: usize,
+    height: usize,
+    bit_depth: u8,
+    color_type: u8,
+    interlaced: bool,
+}
+
+impl Header {
+    fn parse(chunk: &[u8]) -> Result<Self> {
+        if chunk.len() != 13 {
+            return Err(malformed(format!(
+                "IHDR has {} bytes instead of 13",
+                chunk.len()
+            )));
+        }
+
+        let width = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize;
+        let height = u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]) as usize;
+        let (bit_depth, color_type) = (chunk[8], chunk[9]);
+
+        if width == 0 || height == 0 {
+            return Err(malformed(format!("{}x{} is empty", width, height)));
+        }
+
+        let valid_depths: &[u8] = match color_type {
+            0 => &[1, 2, 4, 8, 16],
+            2 | 4 | 6 => &[8, 16],
+            3 => &[1, 2, 4, 8],
+            _ => return Err(malformed(format!("unknown color type {}", color_type))),
+        };
+        if !valid_depths.contains(&bit_depth) {
+            return Err(malformed(format!(
+                "bit depth {} is invalid for color type {}",
+                bit_depth, color_type
+            )));
+        }
+
+        if chunk[10] != 0 || chunk[11] != 0 {
+            return Err(malformed(
+                "unknown compression or filter method".to_string(),
+            ));
+        }
+
+        let interlaced = match chunk[12] {
+            0 => false,
+            1 => true,
+            method => return Err(malformed(format!("unknown interlace method {}", method))),
+        };
+
+        Ok(Header {
+            width,
+            height,
+            bit_depth,
+            color_type,
+            interlaced,
+        })
+    }
+
+    fn channels(&self) -> usize {
+        match self.color_type {
+            2 => 3,
+            4 => 2,
+            6 => 4,
+            _ => 1,
+        }
+    }
+
+    fn bits_per_pixel(&self) -> usize {
+        self.channels() * usize::from(self.bit_depth)
+    }
+
+    // the distance in bytes to the corresponding byte of the pixel to the left
+    fn filter_step(&self) -> usize {
+        (self.bits_per_pixel() / 8).max(1)
+    }
+
+    fn row_bytes(&self, width: usize) -> Option<usize> {
+        width
+            .checked_mul(self.bits_per_pixel())
+            .map(|bits| bits.div_ceil(8))
+    }
+
+    // (x offset, y offset, x step, y step, width, height) of the non-empty
+    // passes
+    fn passes(&self) -> Vec<(usize, usize, usize, usize, usize, usize)> {
+        if !self.interlaced {
+            return vec![(0, 0, 1, 1, self.width, self.height)];
+        }
+
+        ADAM7
+            .iter()
+            .map(|&(x0, y0, dx, dy)| {
+                let width = (self.width + dx - 1 - x0) / dx;
+                let height = (self.height + dy - 1 - y0) / dy;
+                (x0, y0, dx, dy, width, height)
+            })
+            .filter(|&(_, _, _, _, width, height)| width > 0 && height > 0)
+            .collect()
+    }
+
+    fn sample(&self, row: &[u8], index: usize) -> u16 {
+        match self.bit_depth {
+            16 => u16::from_be_bytes([row[2 * index], row[2 * index + 1]]),
+            8 => u16::from(row[index]),
+            depth => {
+                let bit = index * usize::from(depth);
+                let shift = 8 - usize::from(depth) - bit % 8;
+                u16::from((row[bit / 8] >> shift) & ((1 << depth) - 1))
+            }
+        }
+    }
+
+    fn color<T: Scalar>(&self, row: &[u8], pixel: usize, palette: &[[u8; 3]]) -> Result<Color<T>> {
+        let first = pixel * self.channels();
+        let max = f64::from((1u32 << self.bit_depth) - 1);
+        let channel = |index: usize| T::from_f64(f64::from(self.sample(row, first + index)) / max);
+
+        match self.color_type {
+            0 | 4 => {
+                let gray = channel(0);
+                Ok(Color::new(gray, gray, gray))
+            }
+            3 => {
+                let index = self.sample(row, first);
+                let rgb = palette
+                    .get(usize::from(index))
+                    .ok_or_else(|| malformed(format!("palette index {} is out of range", index)))?;
+                let channel = |value: u8| T::from_f64(f64::from(value) / 255.0);
+                Ok(Color::new(
+                    channel(rgb[0]),
+                    channel(rgb[1]),
+                    channel(rgb[2]),
+                ))
+            }
+            _ => Ok(Color::new(channel(0), channel(1), channel(2))),
+        }
+    }
+}
+
+fn read_chunk<'a>(bytes: &'a [u8], position: &mut usize) -> Result<([u8; 4], &'a [u8])> {
+    let start = *position;
+    let header = bytes
+        .get(start..start + 8)
+        .ok_or(Error::TruncatedImage { format: FORMAT })?;
+
+    let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let kind = [header[4], header[5], header[6], header[7]];
+
+    let end = (start + 8)
+        .checked_add(length)
+        .filter(|&end| end + 4 <= bytes.len())
+        .ok_or(Error::TruncatedImage { format: FORMAT })?;
+
+    let stored = u32::from_be_bytes([bytes[end], bytes[end + 1], bytes[end + 2], bytes[end + 3]]);
+    if stored != crc32(&bytes[start + 4..end]) {
+        return Err(malformed(format!(
+            "checksum mismatch in {:?} chunk",
+            String::from_utf8_lossy(&kind)
+        )));
+    }
+
+    *position = end + 4;
+    Ok((kind, &bytes[start + 8..end]))
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    png.extend_from_slice(&(!update_crc(update_crc(!0, kind), data)).to_be_bytes());
+}
+
+fn filter(kind: u8, current: &[u8], previous: &[u8], step: usize) -> Vec<u8> {
+    (0..current.len())
+        .map(|i| {
+            let a = if i >= step { current[i - step] } else { 0 };
+            let b = previous[i];
+            let c = if i >= step { previous[i - step] } else { 0 };
+            current[i].wrapping_sub(predict(kind, a, b, c))
+        })
+        .collect()
+}
+
+fn unfilter(kind: u8, filtered: &[u8], previous: &[u8], step: usize) -> Result<Vec<u8>> {
+    if kind > 4 {
+        return Err(malformed(format!("unknown filter type {}", kind)));
+    }
+
+    let mut current = Vec::with_capacity(filtered.len());
+    for (i, &byte) in filtered.iter().enumerate() {
+        let a = if i >= step { current[i - step] } else { 0 };
+        let b = previous[i];
+        let c = if i >= step { previous[i - step] } else { 0 };
+        current.push(byte.wrapping_add(predict(kind, a, b, c)));
+    }
+
+    Ok(current)
+}
+
+// a is the byte to the left, b the one above and c the one above and to the
+// left
+fn predict(kind: u8, a: u8, b: u8, c: u8) -> u8 {
+    match kind {
+        1 => a,
+        2 => b,
+        3 => ((u16::from(a) + u16::from(b)) / 2) as u8,
+        4 => {
+            let p = i16::from(a) + i16::from(b) - i16::from(c);
+            let (pa, pb, pc) = (
+                (p - i16::from(a)).abs(),
+                (p - i16::from(b)).abs(),
+                (p - i16::from(c)).abs(),
+            );
+
+            if pa <= pb && pa <= pc {
+                a
+            } else if pb <= pc {
+                b
+            } else {
+                c
+            }
+        }
+        _ => 0,
+    }
+}
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+
+    table
+}
+
+fn update_crc(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(crc, |crc, &byte| {
+        CRC_TABLE[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn malformed(reason: String) -> Error {
+    Error::MalformedImage {
+        format: FORMAT,
+        reason,
+    }
+}
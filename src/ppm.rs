@@ -0,0 +1,219 @@
+use crate::canvas::Canvas;
+use crate::dithering::Dither;
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
+use crate::tone_mapping::ExportOptions;
+use crate::tuples::Color;
+
+const FORMAT: &str = "PPM";
+const LINE_LENGTH: usize = 70;
+
+impl<T: Scalar> Canvas<T> {
+    // plain (P3) PPM with 8-bit channels, clamped to [0, 1]
+    pub fn to_ppm(&self) -> String {
+        encode(self.width(), self.height(), &self.to_rgb8(Dither::None))
+    }
+
+    // tone maps and dithers according to the options before quantizing
+    pub fn to_ppm_with(&self, options: &ExportOptions) -> String {
+        let rgb = self.tone_mapped(options).to_rgb8(options.dither);
+        encode(self.width(), self.height(), &rgb)
+    }
+
+    // reads plain (P3) and raw (P6) PPM images with any maxval up to 65535
+    pub fn from_ppm(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes, FORMAT);
+
+        let binary = match reader.token()? {
+            b"P3" => false,
+            b"P6" => true,
+            magic => {
+                return Err(malformed(format!(
+                    "unknown magic number {:?}",
+                    String::from_utf8_lossy(magic)
+                )))
+            }
+        };
+        let width = reader.number("width")?;
+        let height = reader.number("height")?;
+        let maxval = reader.number("maxval")?;
+
+        if maxval == 0 || maxval > 65535 {
+            return Err(malformed(format!("maxval {} is out of range", maxval)));
+        }
+
+        let samples = width
+            .checked_mul(height)
+            .and_then(|pixels| pixels.checked_mul(3))
+            .ok_or_else(|| malformed(format!("{}x{} is too large", width, height)))?;
+
+        let mut values = Vec::with_capacity(samples.min(bytes.len()));
+        if binary {
+            reader.single_whitespace()?;
+
+            let sample_size = if maxval > 255 { 2 } else { 1 };
+            let data = reader.remaining();
+
+            if data.len() < samples * sample_size {
+                return Err(Error::TruncatedImage { format: FORMAT });
+            }
+
+            values.extend(data.chunks(sample_size).take(samples).map(|sample| {
+                sample
+                    .iter()
+                    .fold(0, |value, &byte| (value << 8) | usize::from(byte))
+            }));
+        } else {
+            for _ in 0..samples {
+                values.push(reader.number("sample")?);
+            }
+        }
+
+        if let Some(value) = values.iter().find(|&&value| value > maxval) {
+            return Err(malformed(format!(
+                "sample {} exceeds maxval {}",
+                value, maxval
+            )));
+        }
+
+        let scale = maxval as f64;
+        let pixels = values
+            .chunks(3)
+            .map(|rgb| {
+                Color::new(
+                    T::from_f64(rgb[0] as f64 / scale),
+                    T::from_f64(rgb[1] as f64 / scale),
+                    T::from_f64(rgb[2] as f64 / scale),
+                )
+            })
+            .collect();
+
+        Canvas::from_pixels(width, height, pixels)
+    }
+}
+
+fn encode(width: usize, height: usize, rgb: &[[u8; 3]]) -> String {
+    let mut ppm = format!("P3\n{} {}\n255\n", width, height);
+
+    for row in rgb.chunks(width.max(1)) {
+        let mut line = String::new();
+
+        for value in row.iter().flatten() {
+            let value = value.to_string();
+
+            if !line.is_empty() && line.len() + 1 + value.len() > LINE_LENGTH {
+                ppm.push_str(&line);
+                ppm.push('\n');
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&value);
+        }
+
+        ppm.push_str(&line);
+        ppm.push('\n');
+    }
+
+    ppm
+}
+
+// tokenizer for the whitespace separated headers of the Netpbm family
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    format: &'static str,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8], format: &'static str) -> Self {
+        Reader {
+            bytes,
+            position: 0,
+            format,
+        }
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&byte) = self.bytes.get(self.position) {
+            if byte == b'#' {
+                while let Some(&byte) = self.bytes.get(self.position) {
+                    if byte == b'\n' || byte == b'\r' {
+                        break;
+                    }
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub(crate) fn token(&mut self) -> Result<&'a [u8]> {
+        self.skip_whitespace_and_comments();
+
+        let start = self.position;
+        while let Some(&byte) = self.bytes.get(self.position) {
+            if byte.is_ascii_whitespace() || byte == b'#' {
+                break;
+            }
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return Err(Error::TruncatedImage {
+                format: self.format,
+            });
+        }
+
+        Ok(&self.bytes[start..self.position])
+    }
+
+    pub(crate) fn number(&mut self, name: &str) -> Result<usize> {
+        let token = self.token()?;
+
+        std::str::from_utf8(token)
+            .ok()
+            .filter(|digits| digits.bytes().all(|byte| byte.is_ascii_digit()))
+            .and_then(|digits| digits.parse().ok())
+            .ok_or_else(|| Error::MalformedImage {
+                format: self.format,
+                reason: format!(
+                    "expected {}, found {:?}",
+                    name,
+                    String::from_utf8_lossy(token)
+                ),
+            })
+    }
+
+    // the raster of a binary image starts after exactly one whitespace byte
+    pub(crate) fn single_whitespace(&mut self) -> Result<()> {
+        match self.bytes.get(self.position) {
+            Some(byte) if byte.is_ascii_whitespace() => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(_) => Err(Error::MalformedImage {
+                format: self.format,
+                reason: "expected whitespace before the raster".to_string(),
+            }),
+            None => Err(Error::TruncatedImage {
+                format: self.format,
+            }),
+        }
+    }
+
+    pub(crate) fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+}
+
+fn malformed(reason: String) -> Error {
+    Error::MalformedImage {
+        format: FORMAT,
+        reason,
+    }
+}
@@ -1,4 +1,5 @@
-use crate::tuples::{Point, Vector};
+use crate::canvas::Canvas;
+use crate::tuples::{Color, Point, Vector};
 
 pub struct Projectile {
     pub position: Point,
@@ -10,13 +11,27 @@ pub struct Environment {
     pub wind: Vector,
 }
 
+pub struct Trajectory<'a> {
+    projectile: Projectile,
+    env: &'a Environment,
+}
+
 impl Projectile {
     pub fn new(position: Point, velocity: Vector) -> Self {
         Self { position, velocity }
     }
     pub fn tick(&mut self, env: &Environment) {
-        self.position = &self.position + &self.velocity;
-        self.velocity = &self.velocity + &env.gravity + &env.wind;
+        self.position = self.position + self.velocity;
+        self.velocity = self.velocity + env.gravity + env.wind;
+    }
+    // the positions after each tick, until the projectile hits the ground or
+    // its position stops being finite; without gravity pulling it down that
+    // may never happen, so such trajectories should be limited with take()
+    pub fn trajectory(self, env: &Environment) -> Trajectory<'_> {
+        Trajectory {
+            projectile: self,
+            env,
+        }
     }
 }
 
@@ -25,3 +40,42 @@ impl Environment {
         Self { gravity, wind }
     }
 }
+
+impl Iterator for Trajectory<'_> {
+    type Item = Point;
+
+    // also ends once the position is no longer finite, as a NaN height
+    // would never reach the ground
+    fn next(&mut self) -> Option<Point> {
+        if !is_finite(&self.projectile.position) || self.projectile.position.y() <= 0.0 {
+            return None;
+        }
+
+        self.projectile.tick(self.env);
+        Some(self.projectile.position).filter(is_finite)
+    }
+}
+
+fn is_finite(point: &Point) -> bool {
+    [point.x(), point.y(), point.z()]
+        .iter()
+        .all(|c| c.is_finite())
+}
+
+// world y grows upwards while canvas rows grow downwards, so y = 0 lands on
+// the bottom row; points that fall outside of the canvas are skipped
+pub fn plot_trajectory<I>(canvas: &mut Canvas, positions: I, color: Color)
+where
+    I: IntoIterator<Item = Point>,
+{
+    for position in positions {
+        let x = position.x().round();
+        let y = canvas.height() as f64 - 1.0 - position.y().round();
+
+        if x < 0.0 || y < 0.0 || x >= canvas.width() as f64 || y >= canvas.height() as f64 {
+            continue;
+        }
+
+        canvas.write_pixel(x as usize, y as usize, color);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::approx::ApproxEq;
+use crate::matrices::Matrix;
+use crate::scalar::Scalar;
+use crate::tuples::Vector;
+use duplicate::duplicate;
+use std::ops::Mul;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Quaternion<T = f64> {
+    // w, x, y, z
+    value: [T; 4],
+}
+
+impl<T: Scalar> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion {
+            value: [w, x, y, z],
+        }
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::new(T::ONE, T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn from_axis_angle(axis: &Vector<T>, radians: T) -> Self {
+        let axis = axis.normalize();
+        let half = radians / T::from_f64(2.0);
+        let sin = half.sin();
+
+        Quaternion::new(half.cos(), axis.x() * sin, axis.y() * sin, axis.z() * sin)
+    }
+
+    pub fn w(&self) -> T {
+        self.value[0]
+    }
+
+    pub fn x(&self) -> T {
+        self.value[1]
+    }
+
+    pub fn y(&self) -> T {
+        self.value[2]
+    }
+
+    pub fn z(&self) -> T {
+        self.value[3]
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.value
+            .iter()
+            .zip(other.value.iter())
+            .map(|(&a, &b)| a * b)
+            .sum()
+    }
+
+    pub fn magnitude(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let magnitude = self.magnitude();
+        let [w, x, y, z] = self.value;
+
+        Quaternion::new(w / magnitude, x / magnitude, y / magnitude, z / magnitude)
+    }
+
+    pub fn conjugate(&self) -> Self {
+        let [w, x, y, z] = self.value;
+
+        Quaternion::new(w, -x, -y, -z)
+    }
+
+    pub fn rotate(&self, v: &Vector<T>) -> Vector<T> {
+        let p = Quaternion::new(T::ZERO, v.x(), v.y(), v.z());
+        let rotated = *self * p * self.conjugate();
+
+        Vector::new(rotated.x(), rotated.y(), rotated.z())
+    }
+
+    // expects a unit quaternion
+    pub fn to_matrix(&self) -> Matrix<4, T> {
+        let [w, x, y, z] = self.value;
+        let two = T::from_f64(2.0);
+
+        let mut m = Matrix::identity();
+        m[(0, 0)] = T::ONE - two * (y * y + z * z);
+        m[(0, 1)] = two * (x * y - w * z);
+        m[(0, 2)] = two * (x * z + w * y);
+        m[(1, 0)] = two * (x * y + w * z);
+        m[(1, 1)] = T::ONE - two * (x * x + z * z);
+        m[(1, 2)] = two * (y * z - w * x);
+        m[(2, 0)] = two * (x * z - w * y);
+        m[(2, 1)] = two * (y * z + w * x);
+        m[(2, 2)] = T::ONE - two * (x * x + y * y);
+        m
+    }
+
+    // spherical linear interpolation along the shortest arc; t = 0 yields
+    // self, t = 1 yields other
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let from = self.normalize();
+        let mut to = other.normalize();
+        let mut cos = from.dot(&to);
+
+        if cos < T::ZERO {
+            to = Quaternion {
+                value: to.value.map(|c| -c),
+            };
+            cos = -cos;
+        }
+
+        let (from_weight, to_weight) = if cos > T::from_f64(0.9995) {
+            // nearly parallel, fall back to linear interpolation
+            (T::ONE - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+
+            (((T::ONE - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+
+        let mut value = [T::ZERO; 4];
+        for (v, (&a, &b)) in value.iter_mut().zip(from.value.iter().zip(to.value.iter())) {
+            *v = a * from_weight + b * to_weight;
+        }
+
+        Quaternion { value }.normalize()
+    }
+}
+
+impl<T: Scalar> From<Quaternion<T>> for Matrix<4, T> {
+    fn from(q: Quaternion<T>) -> Self {
+        q.to_matrix()
+    }
+}
+
+impl<T: Scalar> ApproxEq for Quaternion<T> {
+    type Epsilon = T;
+
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.value.relative_eq(&other.value, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon, max_ulps)
+    }
+}
+
+impl<T: Scalar> PartialEq for Quaternion<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
+}
+
+#[duplicate(
+   Lhs Rhs;
+   [ Quaternion<T> ] [ Quaternion<T> ];
+   [ Quaternion<T> ] [ &Quaternion<T> ];
+   [ &Quaternion<T> ] [ Quaternion<T> ];
+   [ &Quaternion<T> ] [ &Quaternion<T> ];
+)]
+impl<T: Scalar> Mul<Rhs> for Lhs {
+    type Output = Quaternion<T>;
+
+    // Hamilton product; the result rotates by rhs first, then by self
+    fn mul(self, rhs: Rhs) -> Self::Output {
+        let [w1, x1, y1, z1] = self.value;
+        let [w2, x2, y2, z2] = rhs.value;
+
+        Quaternion::new(
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+            w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+        )
+    }
+}
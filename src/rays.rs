@@ -0,0 +1,32 @@
+use crate::matrices::Matrix;
+use crate::scalar::Scalar;
+use crate::tuples::{Point, Vector};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Ray<T = f64> {
+    pub origin: Point<T>,
+    pub direction: Vector<T>,
+}
+
+impl<T: Scalar> Ray<T> {
+    pub fn new(origin: Point<T>, direction: Vector<T>) -> Self {
+        Ray { origin, direction }
+    }
+
+    // the point at distance t along the ray, in units of the direction's length
+    pub fn position(&self, t: T) -> Point<T> {
+        self.origin + self.direction * t
+    }
+
+    // the direction is not normalized, so t values stay comparable between
+    // the original and the transformed ray
+    pub fn transform(&self, matrix: &Matrix<4, T>) -> Self {
+        Ray::new(matrix * self.origin, matrix * self.direction)
+    }
+}
+
+impl<T: Scalar> PartialEq for Ray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && self.direction == other.direction
+    }
+}
@@ -0,0 +1,81 @@
+use crate::approx::ApproxEq;
+use duplicate::duplicate;
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Sum
+    + ApproxEq<Epsilon = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    // default tolerance of ApproxEq, also used by the PartialEq impls of
+    // the math types
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+#[duplicate(
+   float_type epsilon narrowed;
+   [ f32 ] [ 0.0001 ] [ value as f32 ];
+   [ f64 ] [ 0.00001 ] [ value ];
+)]
+impl Scalar for float_type {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const EPSILON: Self = epsilon;
+
+    fn abs(self) -> Self {
+        float_type::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        float_type::sqrt(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        float_type::powi(self, n)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        float_type::powf(self, n)
+    }
+
+    fn sin(self) -> Self {
+        float_type::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        float_type::cos(self)
+    }
+
+    fn acos(self) -> Self {
+        float_type::acos(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        narrowed
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::matrices::Matrix;
+use crate::quaternions::Quaternion;
+use crate::scalar::Scalar;
+use crate::tuples::{Color, Point, Tuple, Value, Vector};
+use duplicate::duplicate;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+// Points and vectors serialize as [x, y, z], their w component being implied
+// by the type. Tuples keep all four components, colors are [r, g, b] and
+// quaternions [w, x, y, z]. Matrices are a list of rows.
+
+impl<T: Scalar + Serialize> Serialize for Tuple<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value().serialize(serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Tuple<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w] = <[T; 4]>::deserialize(deserializer)?;
+        Ok(Tuple::new(x, y, z, w))
+    }
+}
+
+#[duplicate(
+   tuple_type;
+   [ Point ];
+   [ Vector ];
+)]
+impl<T: Scalar + Serialize> Serialize for tuple_type<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.x(), self.y(), self.z()].serialize(serializer)
+    }
+}
+
+#[duplicate(
+   tuple_type;
+   [ Point ];
+   [ Vector ];
+)]
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for tuple_type<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z] = <[T; 3]>::deserialize(deserializer)?;
+        Ok(tuple_type::new(x, y, z))
+    }
+}
+
+impl<T: Scalar + Serialize> Serialize for Color<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value().serialize(serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Color<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [red, green, blue] = <[T; 3]>::deserialize(deserializer)?;
+        Ok(Color::new(red, green, blue))
+    }
+}
+
+impl<T: Scalar + Serialize> Serialize for Quaternion<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.w(), self.x(), self.y(), self.z()].serialize(serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Quaternion<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [w, x, y, z] = <[T; 4]>::deserialize(deserializer)?;
+        Ok(Quaternion::new(w, x, y, z))
+    }
+}
+
+impl<const N: usize, T: Scalar + Serialize> Serialize for Matrix<N, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut rows = serializer.serialize_tuple(N)?;
+        for row in self.rows() {
+            rows.serialize_element(&row[..])?;
+        }
+        rows.end()
+    }
+}
+
+impl<'de, const N: usize, T: Scalar + Deserialize<'de>> Deserialize<'de> for Matrix<N, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(N, MatrixVisitor(PhantomData))
+    }
+}
+
+struct MatrixVisitor<const N: usize, T>(PhantomData<T>);
+
+impl<'de, const N: usize, T: Scalar + Deserialize<'de>> Visitor<'de> for MatrixVisitor<N, T> {
+    type Value = Matrix<N, T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} rows of {} numbers", N, N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut m = Matrix::identity();
+
+        for row in 0..N {
+            let values: Vec<T> = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(row, &self))?;
+
+            if values.len() != N {
+                return Err(de::Error::invalid_length(values.len(), &self));
+            }
+            for (column, &value) in values.iter().enumerate() {
+                m[(row, column)] = value;
+            }
+        }
+
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+
+        Ok(m)
+    }
+}
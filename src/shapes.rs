@@ -0,0 +1,87 @@
+use crate::intersections::{Intersection, Intersections, Normal};
+use crate::materials::Material;
+use crate::matrices::Matrix;
+use crate::rays::Ray;
+use crate::scalar::Scalar;
+use crate::transformations::Transform;
+use crate::tuples::{Point, Vector};
+use std::fmt::Debug;
+
+// Geometry in its own object space. Shapes only ever see rays and points that
+// have already been brought into object space by the Object holding them, so
+// they need not know about transforms.
+pub trait Shape<T: Scalar = f64>: Debug {
+    // the t values at which the ray meets the shape, in any order
+    fn local_intersect(&self, ray: &Ray<T>) -> Vec<T>;
+
+    // the normal at a point on the shape, which need not be normalized
+    fn local_normal_at(&self, point: &Point<T>) -> Vector<T>;
+}
+
+// a shape placed in the world, with its transform and material
+#[derive(Debug)]
+pub struct Object<T: Scalar = f64> {
+    shape: Box<dyn Shape<T>>,
+    transform: Transform<T>,
+    // kept alongside the transform, since every intersection needs it
+    inverse: Transform<T>,
+    material: Material<T>,
+}
+
+impl<T: Scalar> Object<T> {
+    pub fn new(shape: impl Shape<T> + 'static) -> Self {
+        Object {
+            shape: Box::new(shape),
+            transform: Matrix::identity(),
+            inverse: Matrix::identity(),
+            material: Material::new(),
+        }
+    }
+
+    pub fn shape(&self) -> &dyn Shape<T> {
+        self.shape.as_ref()
+    }
+
+    pub fn transform(&self) -> &Transform<T> {
+        &self.transform
+    }
+
+    // panics when the transform cannot be inverted, as rays could then not
+    // be brought into object space
+    pub fn set_transform(&mut self, transform: Transform<T>) {
+        self.inverse = transform
+            .inverse()
+            .expect("the transform of an object must be invertible");
+        self.transform = transform;
+    }
+
+    pub fn material(&self) -> &Material<T> {
+        &self.material
+    }
+
+    pub fn set_material(&mut self, material: Material<T>) {
+        self.material = material;
+    }
+
+    pub fn intersect(&self, ray: &Ray<T>) -> Intersections<'_, Self, T> {
+        let local_ray = ray.transform(&self.inverse);
+
+        self.shape
+            .local_intersect(&local_ray)
+            .into_iter()
+            .map(|t| Intersection::new(t, self))
+            .collect()
+    }
+}
+
+impl<T: Scalar> Normal<T> for Object<T> {
+    // the object space normal, brought back into world space with the
+    // transpose of the inverse so that it stays perpendicular to the surface
+    fn normal_at(&self, world_point: &Point<T>) -> Vector<T> {
+        let local_point = self.inverse * world_point;
+        let local_normal = self.shape.local_normal_at(&local_point);
+        let world_normal = self.inverse.transpose() * local_normal;
+
+        world_normal.normalize()
+    }
+}
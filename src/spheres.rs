@@ -0,0 +1,34 @@
+use crate::rays::Ray;
+use crate::scalar::Scalar;
+use crate::shapes::Shape;
+use crate::tuples::{dot, Point, Vector};
+
+// a sphere of radius 1 centered on the origin of its object space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Sphere;
+
+impl<T: Scalar> Shape<T> for Sphere {
+    // the points where the ray enters and leaves the sphere; a tangent ray
+    // touches it twice at the same t
+    fn local_intersect(&self, ray: &Ray<T>) -> Vec<T> {
+        let sphere_to_ray = ray.origin - Point::new(T::ZERO, T::ZERO, T::ZERO);
+
+        let a = dot(&ray.direction, &ray.direction);
+        let b = T::from_f64(2.0) * dot(&ray.direction, &sphere_to_ray);
+        let c = dot(&sphere_to_ray, &sphere_to_ray) - T::ONE;
+        let discriminant = b * b - T::from_f64(4.0) * a * c;
+
+        if discriminant < T::ZERO {
+            return Vec::new();
+        }
+
+        let root = discriminant.sqrt();
+        let two_a = T::from_f64(2.0) * a;
+
+        vec![(-b - root) / two_a, (-b + root) / two_a]
+    }
+
+    fn local_normal_at(&self, point: &Point<T>) -> Vector<T> {
+        *point - Point::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+}
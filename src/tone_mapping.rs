@@ -0,0 +1,114 @@
+use crate::canvas::Canvas;
+use crate::dithering::Dither;
+use crate::scalar::Scalar;
+use crate::tuples::Color;
+
+// keeps black pixels from sending the log average to zero
+const LOG_DELTA: f64 = 1e-4;
+// middle gray, the usual target of auto-exposure
+pub const DEFAULT_KEY: f64 = 0.18;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Exposure {
+    // scales by 2^stops
+    Stops(f64),
+    // scales so that the log-average luminance of the canvas maps to key
+    Auto { key: f64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToneMap {
+    // leaves colors as they are, so the 8-bit conversion clamps them
+    Clamp,
+    // L / (1 + L) on the luminance
+    Reinhard,
+    // L (1 + L / white^2) / (1 + L) on the luminance, mapping white to 1
+    ReinhardExtended { white: f64 },
+    // Narkowicz's fit of the ACES filmic curve, per channel
+    Aces,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExportOptions {
+    pub exposure: Exposure,
+    pub tone_map: ToneMap,
+    pub dither: Dither,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            exposure: Exposure::Stops(0.0),
+            tone_map: ToneMap::Clamp,
+            dither: Dither::None,
+        }
+    }
+}
+
+impl ToneMap {
+    pub fn apply<T: Scalar>(&self, color: Color<T>) -> Color<T> {
+        match *self {
+            ToneMap::Clamp => color,
+            ToneMap::Reinhard => scale_luminance(color, |l| l / (1.0 + l)),
+            ToneMap::ReinhardExtended { white } => {
+                scale_luminance(color, |l| l * (1.0 + l / (white * white)) / (1.0 + l))
+            }
+            ToneMap::Aces => {
+                let curve = |c: T| {
+                    let x = c.to_f64().max(0.0);
+                    let mapped = (x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14);
+                    T::from_f64(mapped.min(1.0))
+                };
+                Color::new(
+                    curve(color.red()),
+                    curve(color.green()),
+                    curve(color.blue()),
+                )
+            }
+        }
+    }
+}
+
+impl<T: Scalar> Canvas<T> {
+    // exp(mean(ln(delta + luminance))), the geometric mean of the luminance
+    pub fn log_average_luminance(&self) -> T {
+        if self.pixels().is_empty() {
+            return T::ZERO;
+        }
+
+        let sum: f64 = self
+            .pixels()
+            .iter()
+            .map(|pixel| (LOG_DELTA + pixel.luminance().to_f64().max(0.0)).ln())
+            .sum();
+
+        T::from_f64((sum / self.pixels().len() as f64).exp())
+    }
+
+    pub fn tone_mapped(&self, options: &ExportOptions) -> Self {
+        let scale = match options.exposure {
+            Exposure::Stops(stops) => 2f64.powf(stops),
+            Exposure::Auto { key } => key / self.log_average_luminance().to_f64(),
+        };
+        let scale = T::from_f64(scale);
+
+        let pixels = self
+            .pixels()
+            .iter()
+            .map(|&pixel| options.tone_map.apply(pixel * scale))
+            .collect();
+
+        // the dimensions are unchanged
+        Canvas::from_pixels(self.width(), self.height(), pixels).unwrap()
+    }
+}
+
+fn scale_luminance<T: Scalar>(color: Color<T>, curve: impl Fn(f64) -> f64) -> Color<T> {
+    let luminance = color.luminance().to_f64();
+
+    if luminance <= 0.0 {
+        return Color::black();
+    }
+
+    color * T::from_f64(curve(luminance) / luminance)
+}
@@ -0,0 +1,90 @@
+use crate::matrices::Matrix;
+use crate::scalar::Scalar;
+
+pub type Transform<T = f64> = Matrix<4, T>;
+
+pub fn translation<T: Scalar>(x: T, y: T, z: T) -> Transform<T> {
+    let mut m = Matrix::identity();
+    m[(0, 3)] = x;
+    m[(1, 3)] = y;
+    m[(2, 3)] = z;
+    m
+}
+
+pub fn scaling<T: Scalar>(x: T, y: T, z: T) -> Transform<T> {
+    let mut m = Matrix::identity();
+    m[(0, 0)] = x;
+    m[(1, 1)] = y;
+    m[(2, 2)] = z;
+    m
+}
+
+pub fn rotation_x<T: Scalar>(radians: T) -> Transform<T> {
+    let (sin, cos) = (radians.sin(), radians.cos());
+    let mut m = Matrix::identity();
+    m[(1, 1)] = cos;
+    m[(1, 2)] = -sin;
+    m[(2, 1)] = sin;
+    m[(2, 2)] = cos;
+    m
+}
+
+pub fn rotation_y<T: Scalar>(radians: T) -> Transform<T> {
+    let (sin, cos) = (radians.sin(), radians.cos());
+    let mut m = Matrix::identity();
+    m[(0, 0)] = cos;
+    m[(0, 2)] = sin;
+    m[(2, 0)] = -sin;
+    m[(2, 2)] = cos;
+    m
+}
+
+pub fn rotation_z<T: Scalar>(radians: T) -> Transform<T> {
+    let (sin, cos) = (radians.sin(), radians.cos());
+    let mut m = Matrix::identity();
+    m[(0, 0)] = cos;
+    m[(0, 1)] = -sin;
+    m[(1, 0)] = sin;
+    m[(1, 1)] = cos;
+    m
+}
+
+pub fn shearing<T: Scalar>(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Transform<T> {
+    let mut m = Matrix::identity();
+    m[(0, 1)] = xy;
+    m[(0, 2)] = xz;
+    m[(1, 0)] = yx;
+    m[(1, 2)] = yz;
+    m[(2, 0)] = zx;
+    m[(2, 1)] = zy;
+    m
+}
+
+// Each call applies its transformation after the ones already chained, so
+// `identity().rotate_x(a).translate(x, y, z)` rotates first and translates
+// second.
+impl<T: Scalar> Matrix<4, T> {
+    pub fn translate(self, x: T, y: T, z: T) -> Self {
+        translation(x, y, z) * self
+    }
+
+    pub fn scale(self, x: T, y: T, z: T) -> Self {
+        scaling(x, y, z) * self
+    }
+
+    pub fn rotate_x(self, radians: T) -> Self {
+        rotation_x(radians) * self
+    }
+
+    pub fn rotate_y(self, radians: T) -> Self {
+        rotation_y(radians) * self
+    }
+
+    pub fn rotate_z(self, radians: T) -> Self {
+        rotation_z(radians) * self
+    }
+
+    pub fn shear(self, xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        shearing(xy, xz, yx, yz, zx, zy) * self
+    }
+}
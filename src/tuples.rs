@@ -1,96 +1,268 @@
+use crate::approx::ApproxEq;
+use crate::error::{Error, Result};
+use crate::scalar::Scalar;
 use duplicate::duplicate;
+use std::convert::TryFrom;
 use std::ops::{Add, Div, Mul, Neg, Sub};
-use std::vec::Vec;
 
-#[derive(Debug, Clone)]
-pub struct Tuple {
-    value: Vec<f32>,
+#[derive(Debug, Clone, Copy)]
+pub struct Tuple<T = f64> {
+    value: [T; 4],
 }
 
-#[derive(Debug, Clone)]
-pub struct Point {
-    value: Vec<f32>,
+#[derive(Debug, Clone, Copy)]
+pub struct Point<T = f64> {
+    value: [T; 4],
 }
 
-#[derive(Debug, Clone)]
-pub struct Vector {
-    value: Vec<f32>,
+#[derive(Debug, Clone, Copy)]
+pub struct Vector<T = f64> {
+    value: [T; 4],
 }
 
-#[derive(Debug, Clone)]
-pub struct Color {
-    pub value: Vec<f32>,
+#[derive(Debug, Clone, Copy)]
+pub struct Color<T = f64> {
+    value: [T; 3],
 }
 
 pub trait Value {
-    fn value(&self) -> &Vec<f32>;
+    type Components;
+
+    fn value(&self) -> &Self::Components;
 }
 
-impl Tuple {
-    pub fn new(value: Vec<f32>) -> Self {
-        Tuple { value }
+impl<T: Scalar> Tuple<T> {
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Tuple {
+            value: [x, y, z, w],
+        }
+    }
+
+    pub fn w(&self) -> T {
+        self.value[3]
     }
 }
 
-impl Point {
-    pub fn new(value: Vec<f32>) -> Self {
-        let mut p = value.to_vec();
-        p.push(1.0);
-        Point { value: p }
+impl<T: Scalar> Point<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point {
+            value: [x, y, z, T::ONE],
+        }
     }
 }
 
-impl Vector {
-    pub fn new(value: Vec<f32>) -> Self {
-        let mut v = value.to_vec();
-        v.push(0.0);
-        Vector { value: v }
+impl<T: Scalar> Vector<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vector {
+            value: [x, y, z, T::ZERO],
+        }
     }
 
-    pub fn magnitude(&self) -> f32 {
-        self.value.iter().map(|x| x.powi(2)).sum::<f32>().sqrt()
+    pub fn magnitude(&self) -> T {
+        self.value.iter().map(|x| x.powi(2)).sum::<T>().sqrt()
     }
 
     pub fn normalize(&self) -> Self {
         let magnitude = self.magnitude();
 
         Self {
-            value: self.value.iter().map(|x| x / magnitude).collect(),
+            value: map(&self.value, |x| x / magnitude),
         }
     }
+
+    pub fn reflect(&self, normal: &Vector<T>) -> Self {
+        *self - *normal * (T::from_f64(2.0) * dot(self, normal))
+    }
+
+    // self is the incoming direction and normal points against it; returns
+    // None on total internal reflection
+    pub fn refract(&self, normal: &Vector<T>, eta_ratio: T) -> Option<Self> {
+        let cos_i = -dot(self, normal);
+        let sin2_t = eta_ratio.powi(2) * (T::ONE - cos_i.powi(2));
+
+        if sin2_t > T::ONE {
+            return None;
+        }
+
+        let cos_t = (T::ONE - sin2_t).sqrt();
+
+        Some(*self * eta_ratio + *normal * (eta_ratio * cos_i - cos_t))
+    }
 }
 
-impl Color {
-    pub fn new(value: Vec<f32>) -> Self {
-        Color { value }
+impl<T: Scalar> Color<T> {
+    pub fn new(red: T, green: T, blue: T) -> Self {
+        Color {
+            value: [red, green, blue],
+        }
+    }
+
+    pub fn black() -> Self {
+        Color::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn white() -> Self {
+        Color::new(T::ONE, T::ONE, T::ONE)
+    }
+
+    // accepts "#rrggbb", "rrggbb", "#rgb" and "rgb"; the channels are taken
+    // as they are, without decoding the sRGB transfer function
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || Error::InvalidHex(hex.to_string());
+
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let channel = |range: std::ops::Range<usize>, scale: f64| {
+            u8::from_str_radix(&digits[range], 16).map(|c| T::from_f64(f64::from(c) / scale))
+        };
+
+        let channels = match digits.len() {
+            3 => [
+                channel(0..1, 15.0),
+                channel(1..2, 15.0),
+                channel(2..3, 15.0),
+            ],
+            6 => [
+                channel(0..2, 255.0),
+                channel(2..4, 255.0),
+                channel(4..6, 255.0),
+            ],
+            _ => return Err(invalid()),
+        };
+
+        match channels {
+            [Ok(red), Ok(green), Ok(blue)] => Ok(Color::new(red, green, blue)),
+            _ => Err(invalid()),
+        }
+    }
+
+    pub fn red(&self) -> T {
+        self.value[0]
+    }
+
+    pub fn green(&self) -> T {
+        self.value[1]
+    }
+
+    pub fn blue(&self) -> T {
+        self.value[2]
+    }
+
+    pub fn clamp(&self) -> Self {
+        Self {
+            value: map(&self.value, |c| {
+                if c < T::ZERO {
+                    T::ZERO
+                } else if c > T::ONE {
+                    T::ONE
+                } else {
+                    c
+                }
+            }),
+        }
+    }
+
+    // clamps to [0, 1] and rounds to the nearest of the 256 levels
+    pub fn to_rgb8(&self) -> [u8; 3] {
+        let [red, green, blue] = self.clamp().value;
+        let quantize = |c: T| (c.to_f64() * 255.0).round() as u8;
+
+        [quantize(red), quantize(green), quantize(blue)]
+    }
+
+    // applies the sRGB transfer function to linear channel values
+    pub fn to_srgb(&self) -> Self {
+        Self {
+            value: map(&self.value, |c| {
+                if c <= T::from_f64(0.0031308) {
+                    c * T::from_f64(12.92)
+                } else {
+                    T::from_f64(1.055) * c.powf(T::from_f64(1.0 / 2.4)) - T::from_f64(0.055)
+                }
+            }),
+        }
+    }
+
+    // inverse of to_srgb
+    pub fn to_linear(&self) -> Self {
+        Self {
+            value: map(&self.value, |c| {
+                if c <= T::from_f64(0.04045) {
+                    c / T::from_f64(12.92)
+                } else {
+                    ((c + T::from_f64(0.055)) / T::from_f64(1.055)).powf(T::from_f64(2.4))
+                }
+            }),
+        }
+    }
+
+    // relative luminance of linear Rec. 709 primaries
+    pub fn luminance(&self) -> T {
+        T::from_f64(0.2126) * self.red()
+            + T::from_f64(0.7152) * self.green()
+            + T::from_f64(0.0722) * self.blue()
     }
 }
 
-pub fn dot(v1: &Vector, v2: &Vector) -> f32 {
+pub fn dot<T: Scalar>(v1: &Vector<T>, v2: &Vector<T>) -> T {
     v1.value()
         .iter()
         .zip(v2.value().iter())
-        .map(|(x, y)| x * y)
-        .sum::<f32>()
+        .map(|(&x, &y)| x * y)
+        .sum::<T>()
 }
 
-pub fn cross(v1: &Vector, v2: &Vector) -> Vector {
-    let mut f1: Vec<f32> = v1.value().to_vec();
-    let mut f2: Vec<f32> = v2.value().to_vec();
-    let mut result: Vec<f32> = Vec::with_capacity(4);
+// Schlick's approximation of the Fresnel reflectance, for light leaving
+// a medium with refractive index n1 and entering one with index n2
+pub fn schlick<T: Scalar>(eyev: &Vector<T>, normal: &Vector<T>, n1: T, n2: T) -> T {
+    let mut cos = dot(eyev, normal);
+
+    if n1 > n2 {
+        let n = n1 / n2;
+        let sin2_t = n.powi(2) * (T::ONE - cos.powi(2));
+
+        if sin2_t > T::ONE {
+            return T::ONE;
+        }
+
+        cos = (T::ONE - sin2_t).sqrt();
+    }
 
-    f1.pop();
-    f2.pop();
-    f1.rotate_left(1);
-    f2.rotate_right(1);
+    let r0 = ((n1 - n2) / (n1 + n2)).powi(2);
 
-    for (index, _value) in f1.iter().enumerate() {
-        result.push(f1[index] * f2[index] - f1[(index + 1) % 3] * f2[(index + 2) % 3]);
+    r0 + (T::ONE - r0) * (T::ONE - cos).powi(5)
+}
+
+pub fn cross<T: Scalar>(v1: &Vector<T>, v2: &Vector<T>) -> Vector<T> {
+    let [x1, y1, z1, _] = v1.value;
+    let [x2, y2, z2, _] = v2.value;
+
+    Vector {
+        value: [
+            y1 * z2 - z1 * y2,
+            z1 * x2 - x1 * z2,
+            x1 * y2 - y1 * x2,
+            T::ZERO,
+        ],
     }
+}
 
-    result.push(0.0);
+#[duplicate(
+   tuple_type components;
+   [ Tuple ] [ [T; 4] ];
+   [ Point ] [ [T; 4] ];
+   [ Vector ] [ [T; 4] ];
+   [ Color ] [ [T; 3] ];
+)]
+impl<T: Scalar> Value for tuple_type<T> {
+    type Components = components;
 
-    Vector { value: result }
+    fn value(&self) -> &Self::Components {
+        &self.value
+    }
 }
 
 #[duplicate(
@@ -98,11 +270,73 @@ pub fn cross(v1: &Vector, v2: &Vector) -> Vector {
    [ Tuple ];
    [ Point ];
    [ Vector ];
-   [ Color ];
 )]
-impl Value for tuple_type {
-    fn value(&self) -> &Vec<f32> {
-        &self.value
+impl<T: Scalar> tuple_type<T> {
+    pub fn x(&self) -> T {
+        self.value[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.value[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.value[2]
+    }
+}
+
+#[duplicate(
+   tuple_type dimension components;
+   [ Tuple ] [ 4 ] [ [T::ZERO; 4] ];
+   [ Point ] [ 3 ] [ [T::ZERO, T::ZERO, T::ZERO, T::ONE] ];
+   [ Vector ] [ 3 ] [ [T::ZERO; 4] ];
+   [ Color ] [ 3 ] [ [T::ZERO; 3] ];
+)]
+impl<T: Scalar> TryFrom<&[T]> for tuple_type<T> {
+    type Error = Error;
+
+    fn try_from(value: &[T]) -> Result<Self> {
+        if value.len() != dimension {
+            return Err(Error::DimensionMismatch {
+                expected: dimension,
+                found: value.len(),
+            });
+        }
+
+        let mut t = components;
+        t[..dimension].copy_from_slice(value);
+        Ok(Self { value: t })
+    }
+}
+
+#[duplicate(
+   tuple_type w_component;
+   [ Point ] [ T::ONE ];
+   [ Vector ] [ T::ZERO ];
+)]
+impl<T: Scalar> TryFrom<Tuple<T>> for tuple_type<T> {
+    type Error = Error;
+
+    fn try_from(tuple: Tuple<T>) -> Result<Self> {
+        if !tuple.w().approx_eq(&w_component) {
+            return Err(Error::InvalidW {
+                expected: w_component.to_f64(),
+                found: tuple.w().to_f64(),
+            });
+        }
+
+        Ok(Self { value: tuple.value })
+    }
+}
+
+#[duplicate(
+   tuple_type;
+   [ Point ];
+   [ Vector ];
+)]
+impl<T: Scalar> From<tuple_type<T>> for Tuple<T> {
+    fn from(t: tuple_type<T>) -> Self {
+        Tuple { value: t.value }
     }
 }
 
@@ -113,37 +347,60 @@ impl Value for tuple_type {
    [ Vector ];
    [ Color ];
 )]
-impl PartialEq for tuple_type {
-    fn eq(&self, other: &Self) -> bool {
-        let self_iter = self.value.iter();
-        let other_iter = other.value().iter();
+impl<T: Scalar> ApproxEq for tuple_type<T> {
+    type Epsilon = T;
 
-        let unequal_tuple = self_iter.zip(other_iter).find(|(x, y)| !equal(x, y));
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
 
-        match unequal_tuple {
-            None => true,
-            _ => false,
-        }
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.value.relative_eq(&other.value, epsilon, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon, max_ulps)
     }
 }
 
-impl Eq for Tuple {}
-impl Eq for Point {}
-impl Eq for Vector {}
+#[duplicate(
+   tuple_type;
+   [ Tuple ];
+   [ Point ];
+   [ Vector ];
+   [ Color ];
+)]
+impl<T: Scalar> PartialEq for tuple_type<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.approx_eq(other)
+    }
+}
 
 #[duplicate(
    Lhs Rhs Output_type;
    #[
    Lhs_nested Rhs_nested Output_nested;
-    [ Tuple ] [ Tuple ] [Tuple];
-    [ Tuple ] [ Point ] [ Tuple ];
-    [ Tuple ] [ Vector ] [ Tuple ];
-    [ Point ] [ Tuple ] [ Tuple ];
-    [ Point ] [ Vector ] [ Point ];
-    [ Vector ] [ Vector ] [ Vector ];
-    [ Vector ] [ Point ] [ Point ];
-    [ Vector ] [ Tuple ] [ Tuple ];
-    [ Color ] [ Color ] [ Color ];
+    [ Tuple<T> ] [ Tuple<T> ] [ Tuple<T> ];
+    [ Tuple<T> ] [ Point<T> ] [ Tuple<T> ];
+    [ Tuple<T> ] [ Vector<T> ] [ Tuple<T> ];
+    [ Point<T> ] [ Tuple<T> ] [ Tuple<T> ];
+    [ Point<T> ] [ Vector<T> ] [ Point<T> ];
+    [ Vector<T> ] [ Vector<T> ] [ Vector<T> ];
+    [ Vector<T> ] [ Point<T> ] [ Point<T> ];
+    [ Vector<T> ] [ Tuple<T> ] [ Tuple<T> ];
+    [ Color<T> ] [ Color<T> ] [ Color<T> ];
    ][
     [ Lhs_nested ] [ Rhs_nested ] [ Output_nested ];
     [ Lhs_nested ] [ &Rhs_nested ] [ Output_nested ];
@@ -151,15 +408,12 @@ impl Eq for Vector {}
     [ &Lhs_nested ] [ &Rhs_nested ] [ Output_nested ];
    ]
 )]
-impl Add<Rhs> for Lhs {
+impl<T: Scalar> Add<Rhs> for Lhs {
     type Output = Output_type;
 
     fn add(self, rhs: Rhs) -> Self::Output {
-        let lhs_iter = self.value.iter();
-        let rhs_iter = rhs.value().iter();
-
         Self::Output {
-            value: lhs_iter.zip(rhs_iter).map(|(&x, &y)| x + y).collect(),
+            value: zip_map(&self.value, rhs.value(), |x, y| x + y),
         }
     }
 }
@@ -167,10 +421,10 @@ impl Add<Rhs> for Lhs {
 #[duplicate(
    Lhs Rhs Output_type;
    #[ Lhs_inner Rhs_inner Output_inner;
-   [ Point ] [ Point ] [ Vector ];
-   [ Point ] [ Vector ] [ Point ];
-   [ Vector ] [ Vector ] [ Vector ];
-   [ Color ] [ Color ] [ Color ];
+   [ Point<T> ] [ Point<T> ] [ Vector<T> ];
+   [ Point<T> ] [ Vector<T> ] [ Point<T> ];
+   [ Vector<T> ] [ Vector<T> ] [ Vector<T> ];
+   [ Color<T> ] [ Color<T> ] [ Color<T> ];
    ]
    [
    [ Lhs_inner ] [ Rhs_inner ] [ Output_inner];
@@ -179,15 +433,12 @@ impl Add<Rhs> for Lhs {
    [ &Lhs_inner ] [  Rhs_inner ] [ Output_inner];
    ]
 )]
-impl Sub<Rhs> for Lhs {
+impl<T: Scalar> Sub<Rhs> for Lhs {
     type Output = Output_type;
 
     fn sub(self, rhs: Rhs) -> Self::Output {
-        let lhs_iter = self.value.iter();
-        let rhs_iter = rhs.value().iter();
-
         Self::Output {
-            value: lhs_iter.zip(rhs_iter).map(|(&x, &y)| x - y).collect(),
+            value: zip_map(&self.value, rhs.value(), |x, y| x - y),
         }
     }
 }
@@ -197,14 +448,14 @@ impl Sub<Rhs> for Lhs {
    [ Tuple ];
    [ Vector ];
 )]
-impl Neg for tuple_type {
+impl<T: Scalar> Neg for tuple_type<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
         Self::Output {
-            // multiply x by (-1.0); -x results in the last component
+            // subtract x from 0.0; -x results in the last component
             // being equal to -0.0
-            value: self.value.iter().map(|&x| x * (-1.0)).collect(),
+            value: map(&self.value, |x| T::ZERO - x),
         }
     }
 }
@@ -215,27 +466,22 @@ impl Neg for tuple_type {
    [ Vector ];
    [ Color ];
 )]
-impl Mul<f32> for tuple_type {
+impl<T: Scalar> Mul<T> for tuple_type<T> {
     type Output = Self;
 
-    fn mul(self, rhs: f32) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Self::Output {
-            value: self.value.iter().map(|&x| x * rhs).collect(),
+            value: map(&self.value, |x| x * rhs),
         }
     }
 }
 
-impl Mul<Color> for Color {
+impl<T: Scalar> Mul<Color<T>> for Color<T> {
     type Output = Self;
 
-    fn mul(self, rhs: Color) -> Self::Output {
+    fn mul(self, rhs: Color<T>) -> Self::Output {
         Self::Output {
-            value: self
-                .value
-                .iter()
-                .zip(rhs.value.iter())
-                .map(|(x, y)| x * y)
-                .collect(),
+            value: zip_map(&self.value, &rhs.value, |x, y| x * y),
         }
     }
 }
@@ -245,19 +491,28 @@ impl Mul<Color> for Color {
    [ Tuple ];
    [ Vector ];
 )]
-impl Div<f32> for tuple_type {
+impl<T: Scalar> Div<T> for tuple_type<T> {
     type Output = Self;
 
-    fn div(self, rhs: f32) -> Self::Output {
+    fn div(self, rhs: T) -> Self::Output {
         Self::Output {
-            value: self.value.iter().map(|&x| x / rhs).collect(),
+            value: map(&self.value, |x| x / rhs),
         }
     }
 }
 
-fn equal(x: &f32, y: &f32) -> bool {
-    if (x - y).abs() < f32::EPSILON * 10.0 {
-        return true;
+fn map<T: Scalar, const N: usize>(a: &[T; N], f: impl Fn(T) -> T) -> [T; N] {
+    let mut result = [T::ZERO; N];
+    for (r, &x) in result.iter_mut().zip(a.iter()) {
+        *r = f(x);
+    }
+    result
+}
+
+fn zip_map<T: Scalar, const N: usize>(a: &[T; N], b: &[T; N], f: impl Fn(T, T) -> T) -> [T; N] {
+    let mut result = [T::ZERO; N];
+    for (r, (&x, &y)) in result.iter_mut().zip(a.iter().zip(b.iter())) {
+        *r = f(x, y);
     }
-    return false;
+    result
 }
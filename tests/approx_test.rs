@@ -0,0 +1,85 @@
+use ray_tracer_challenge::approx::ApproxEq;
+use ray_tracer_challenge::tuples::{Color, Point, Tuple, Vector};
+use ray_tracer_challenge::{assert_approx_eq, assert_approx_ne};
+
+#[test]
+fn comparing_floats_with_an_absolute_tolerance() {
+    assert!(1.0_f64.abs_diff_eq(&1.000009, 0.00001));
+    assert!(!1.0_f64.abs_diff_eq(&1.00002, 0.00001));
+    assert!(1.0_f32.abs_diff_eq(&1.00009, 0.0001));
+}
+
+#[test]
+fn comparing_floats_with_a_relative_tolerance() {
+    assert!(1_000_000.0_f64.relative_eq(&1_000_001.0, 0.00001, 0.00001));
+    assert!(!1_000_000.0_f64.relative_eq(&1_000_100.0, 0.00001, 0.00001));
+    assert!(!f64::INFINITY.relative_eq(&f64::MAX, 0.00001, 0.00001));
+    assert!(f64::INFINITY.relative_eq(&f64::INFINITY, 0.00001, 0.00001));
+}
+
+#[test]
+fn comparing_floats_by_units_in_the_last_place() {
+    let x = 100_000.0_f32;
+    let next = f32::from_bits(x.to_bits() + 1);
+    let further = f32::from_bits(x.to_bits() + 5);
+
+    assert!(x.ulps_eq(&next, 0.0, 1));
+    assert!(!x.ulps_eq(&further, 0.0, 4));
+    assert!(!1.0_f64.ulps_eq(&-1.0, 0.0, u32::MAX));
+    assert!(0.0_f64.ulps_eq(&-0.0, 0.0, 0));
+}
+
+#[test]
+fn comparing_tuples_with_a_custom_tolerance() {
+    let t1 = Tuple::new(1.0, 2.0, 3.0, 1.0);
+    let t2 = Tuple::new(1.001, 2.0, 3.0, 1.0);
+
+    assert!(t1 != t2);
+    assert!(t1.abs_diff_eq(&t2, 0.01));
+    assert!(!t1.abs_diff_eq(&t2, 0.0001));
+}
+
+#[test]
+fn comparing_points_vectors_and_colors_relatively() {
+    let p1 = Point::new(1000.0, 0.0, 0.0);
+    let p2 = Point::new(1000.01, 0.0, 0.0);
+    let v1 = Vector::new(0.0, 1000.0, 0.0);
+    let v2 = Vector::new(0.0, 1000.01, 0.0);
+    let c1 = Color::new(0.0, 0.0, 1000.0);
+    let c2 = Color::new(0.0, 0.0, 1000.01);
+
+    assert!(p1 != p2);
+    assert!(p1.relative_eq(&p2, 0.00001, 0.0001));
+    assert!(v1.relative_eq(&v2, 0.00001, 0.0001));
+    assert!(c1.relative_eq(&c2, 0.00001, 0.0001));
+}
+
+#[test]
+fn asserting_approximate_equality() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    let x = 100_000.0_f32;
+
+    assert_approx_eq!(v, Vector::new(1.000001, 2.0, 3.0));
+    assert_approx_eq!(v, Vector::new(1.01, 2.0, 3.0), epsilon = 0.1);
+    assert_approx_eq!(1000.0, 1000.5, max_relative = 0.001);
+    assert_approx_eq!(1000.0, 1000.5, epsilon = 0.0, max_relative = 0.001);
+    assert_approx_eq!(x, f32::from_bits(x.to_bits() + 2), max_ulps = 2);
+    assert_approx_eq!(1.0_f32, 1.5, epsilon = 0.5, max_ulps = 0);
+}
+
+#[test]
+fn asserting_approximate_inequality() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    let x = 100_000.0_f32;
+
+    assert_approx_ne!(v, Vector::new(1.01, 2.0, 3.0));
+    assert_approx_ne!(v, Vector::new(1.01, 2.0, 3.0), epsilon = 0.001);
+    assert_approx_ne!(1000.0, 1002.0, max_relative = 0.001);
+    assert_approx_ne!(x, f32::from_bits(x.to_bits() + 3), max_ulps = 2);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed")]
+fn asserting_approximate_equality_panics_on_mismatch() {
+    assert_approx_eq!(Color::new(0.1, 0.2, 0.3), Color::new(0.2, 0.2, 0.3));
+}
@@ -0,0 +1,97 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::tuples::Color;
+
+#[test]
+fn creating_a_canvas() {
+    let c: Canvas = Canvas::new(10, 20);
+
+    assert_eq!(10, c.width());
+    assert_eq!(20, c.height());
+    assert!(c.pixels().iter().all(|&p| p == Color::new(0.0, 0.0, 0.0)));
+}
+
+#[test]
+fn writing_pixels_to_a_canvas() {
+    let mut c = Canvas::new(10, 20);
+    let red = Color::new(1.0, 0.0, 0.0);
+
+    c.write_pixel(2, 3, red);
+
+    assert_eq!(red, c.pixel_at(2, 3));
+    assert_eq!(Color::new(0.0, 0.0, 0.0), c.pixel_at(3, 2));
+}
+
+#[test]
+#[should_panic(expected = "outside of the 10x20 canvas")]
+fn writing_a_pixel_outside_of_the_canvas() {
+    let mut c = Canvas::new(10, 20);
+
+    c.write_pixel(10, 0, Color::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn filling_a_canvas() {
+    let mut c = Canvas::new(4, 3);
+    let color = Color::new(0.2, 0.4, 0.6);
+
+    c.fill(color);
+
+    assert!(c.pixels().iter().all(|&p| p == color));
+}
+
+#[test]
+fn constructing_the_ppm_header() {
+    let c: Canvas = Canvas::new(5, 3);
+    let ppm = c.to_ppm();
+
+    let header: Vec<&str> = ppm.lines().take(3).collect();
+
+    assert_eq!(vec!["P3", "5 3", "255"], header);
+}
+
+#[test]
+fn constructing_the_ppm_pixel_data() {
+    let mut c = Canvas::new(5, 3);
+    c.write_pixel(0, 0, Color::new(1.5, 0.0, 0.0));
+    c.write_pixel(2, 1, Color::new(0.0, 0.5, 0.0));
+    c.write_pixel(4, 2, Color::new(-0.5, 0.0, 1.0));
+
+    let ppm = c.to_ppm();
+    let data: Vec<&str> = ppm.lines().skip(3).collect();
+
+    assert_eq!(
+        vec![
+            "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+            "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+            "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+        ],
+        data
+    );
+}
+
+#[test]
+fn splitting_long_lines_in_ppm_files() {
+    let mut c = Canvas::new(10, 2);
+    c.fill(Color::new(1.0, 0.8, 0.6));
+
+    let ppm = c.to_ppm();
+    let data: Vec<&str> = ppm.lines().skip(3).collect();
+
+    assert_eq!(
+        vec![
+            "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+            "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+            "153 255 204 153 255 204 153 255 204 153 255 204 153",
+        ],
+        data
+    );
+    assert!(ppm.lines().all(|line| line.len() <= 70));
+}
+
+#[test]
+fn ppm_files_are_terminated_by_a_newline_character() {
+    let c: Canvas = Canvas::new(5, 3);
+
+    assert!(c.to_ppm().ends_with('\n'));
+}
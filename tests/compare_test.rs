@@ -0,0 +1,115 @@
+use ray_tracer_challenge::assert_approx_eq;
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::compare::compare;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::tuples::Color;
+
+fn gradient() -> Canvas {
+    let mut c = Canvas::new(4, 2);
+    for y in 0..2 {
+        for x in 0..4 {
+            c.write_pixel(x, y, Color::new(x as f64 / 4.0, y as f64 / 2.0, 0.5));
+        }
+    }
+    c
+}
+
+#[test]
+fn identical_canvases_match() {
+    let comparison = compare(&gradient(), &gradient()).unwrap();
+
+    assert!(comparison.is_match());
+    assert_eq!(0, comparison.mismatched_pixels());
+    assert_eq!(0.0, comparison.max_error());
+    assert_eq!(0.0, comparison.mean_error());
+    assert_eq!(f64::INFINITY, comparison.psnr());
+    assert_eq!(Canvas::new(4, 2), comparison.heatmap());
+}
+
+#[test]
+fn differences_below_the_color_epsilon_are_not_mismatches() {
+    let mut actual = gradient();
+    actual.write_pixel(1, 1, actual.pixel_at(1, 1) + Color::new(0.000001, 0.0, 0.0));
+    let comparison = compare(&gradient(), &actual).unwrap();
+
+    assert!(comparison.is_match());
+    assert_eq!(Color::black(), comparison.heatmap().pixel_at(1, 1));
+}
+
+#[test]
+fn comparing_canvases_reports_error_statistics() {
+    let mut actual = gradient();
+    actual.write_pixel(0, 0, Color::new(0.5, 0.0, 0.5));
+    actual.write_pixel(3, 1, Color::new(0.75, 0.5, 0.25));
+    let comparison = compare(&gradient(), &actual).unwrap();
+
+    assert!(!comparison.is_match());
+    assert_eq!(2, comparison.mismatched_pixels());
+    assert_eq!(0.5, comparison.max_error());
+    assert!(comparison.within(0.5));
+    assert!(!comparison.within(0.4));
+    // 0.5 + 0.25 spread over 24 channels
+    assert_approx_eq!(0.75 / 24.0, comparison.mean_error());
+    assert_approx_eq!(0.3125 / 24.0, comparison.mean_squared_error());
+    assert_approx_eq!(18.85361, comparison.psnr());
+}
+
+#[test]
+fn the_heatmap_highlights_mismatched_pixels() {
+    let mut actual = gradient();
+    actual.write_pixel(0, 0, Color::new(0.5, 0.0, 0.5));
+    actual.write_pixel(3, 1, Color::new(0.75, 0.5, 0.25));
+    let heatmap = compare(&gradient(), &actual).unwrap().heatmap();
+
+    assert_eq!(Color::white(), heatmap.pixel_at(0, 0));
+    assert_eq!(Color::new(1.0, 1.0, 0.0), heatmap.pixel_at(3, 1));
+    assert_eq!(Color::black(), heatmap.pixel_at(1, 0));
+}
+
+#[test]
+fn non_finite_channels_are_infinite_errors() {
+    let expected: Canvas = Canvas::new(3, 2);
+    let mut actual: Canvas = Canvas::new(3, 2);
+    actual.fill(Color::new(f64::NAN, f64::NAN, f64::NAN));
+    actual.write_pixel(1, 0, Color::new(0.0, f64::INFINITY, 0.0));
+    actual.write_pixel(2, 0, Color::new(0.5, 0.0, 0.0));
+
+    let comparison = compare(&expected, &actual).unwrap();
+
+    assert_eq!(6, comparison.mismatched_pixels());
+    assert!(!comparison.within(0.01));
+    assert!(!comparison.within(f64::MAX));
+    assert_eq!(f64::INFINITY, comparison.max_error());
+    assert_eq!(f64::INFINITY, comparison.mean_error());
+
+    let heatmap = comparison.heatmap();
+    assert_eq!(Color::white(), heatmap.pixel_at(0, 0));
+    assert_eq!(Color::white(), heatmap.pixel_at(1, 0));
+    // finite errors are dwarfed by the infinite ones
+    assert_eq!(Color::new(1.0, 0.0, 0.0), heatmap.pixel_at(2, 0));
+}
+
+#[test]
+fn canvases_of_different_sizes_cannot_be_compared() {
+    let result = compare(&gradient(), &Canvas::new(2, 4));
+
+    assert_eq!(
+        Some(Error::SizeMismatch {
+            expected: (4, 2),
+            found: (2, 4),
+        }),
+        result.err()
+    );
+}
+
+#[test]
+fn comparisons_display_a_summary() {
+    let mut actual = gradient();
+    actual.write_pixel(0, 0, Color::new(0.5, 0.0, 0.5));
+    let comparison = compare(&gradient(), &actual).unwrap();
+
+    assert_eq!(
+        "1 of 8 pixels differ, max error 0.500000, mean error 0.020833, PSNR 19.82 dB",
+        comparison.to_string()
+    );
+}
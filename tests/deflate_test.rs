@@ -0,0 +1,85 @@
+use ray_tracer_challenge::deflate::{adler32, deflate, inflate, zlib_compress, zlib_decompress};
+use ray_tracer_challenge::error::Error;
+
+fn sample_data() -> Vec<u8> {
+    let mut data = b"the quick brown fox jumps over the lazy dog ".repeat(50);
+    // a pseudo-random tail that mostly has to be encoded as literals
+    let mut state = 12345u32;
+    for _ in 0..5000 {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        data.push((state >> 16) as u8);
+    }
+    data.extend(vec![0; 1000]);
+    data
+}
+
+#[test]
+fn computing_an_adler32_checksum() {
+    assert_eq!(1, adler32(b""));
+    assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+    assert_eq!(0x149a_302c, adler32(&vec![0xff; 100_000]));
+}
+
+#[test]
+fn deflating_and_inflating_round_trips() {
+    for data in [
+        Vec::new(),
+        b"a".to_vec(),
+        b"abcabcabcabc".to_vec(),
+        sample_data(),
+    ] {
+        assert_eq!(data, inflate(&deflate(&data)).unwrap());
+        assert_eq!(data, zlib_decompress(&zlib_compress(&data)).unwrap());
+    }
+}
+
+#[test]
+fn repetitive_data_is_compressed() {
+    let data = vec![7; 10_000];
+
+    assert!(deflate(&data).len() < 100);
+}
+
+#[test]
+fn inflating_a_stored_block() {
+    let stream = [0x01, 0x03, 0x00, 0xfc, 0xff, b'a', b'b', b'c'];
+
+    assert_eq!(b"abc".to_vec(), inflate(&stream).unwrap());
+}
+
+#[test]
+fn inflating_a_zlib_stream_with_dynamic_huffman_codes() {
+    let expected = b"abcccaaaacaabacaaaacaabccabaabcabaaaaabbaabaababacaabaaabacaaaacbbaabbcaababbbabcbaaabacbabcaaabcaab";
+    let stream = [
+        120, 218, 69, 140, 129, 13, 0, 48, 8, 194, 110, 165, 252, 255, 195, 64, 151, 141, 24, 68,
+        32, 10, 219, 10, 66, 232, 169, 120, 116, 149, 3, 122, 116, 216, 222, 239, 78, 194, 152, 68,
+        227, 27, 86, 106, 62, 136, 3, 134, 130, 38, 35,
+    ];
+
+    assert_eq!(expected.to_vec(), zlib_decompress(&stream).unwrap());
+}
+
+#[test]
+fn corrupt_streams_are_rejected() {
+    let mut stream = zlib_compress(b"hello, hello, hello");
+    let last = stream.len() - 1;
+    stream[last] ^= 1;
+
+    assert_eq!(
+        Err(Error::MalformedDeflate(
+            "zlib checksum mismatch".to_string()
+        )),
+        zlib_decompress(&stream)
+    );
+    assert_eq!(
+        Err(Error::MalformedDeflate("invalid block type".to_string())),
+        inflate(&[0x07])
+    );
+    assert_eq!(
+        Err(Error::MalformedDeflate(
+            "stored block length mismatch".to_string()
+        )),
+        inflate(&[0x01, 0x03, 0x00, 0x00, 0x00])
+    );
+    assert!(inflate(&deflate(b"truncated")[..3]).is_err());
+}
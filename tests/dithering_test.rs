@@ -0,0 +1,111 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::dithering::Dither;
+use ray_tracer_challenge::tone_mapping::ExportOptions;
+use ray_tracer_challenge::tuples::Color;
+
+const DITHERS: [Dither; 3] = [Dither::FloydSteinberg, Dither::Bayer, Dither::BlueNoise];
+
+fn constant(width: usize, height: usize, value: f64) -> Canvas {
+    let mut c = Canvas::new(width, height);
+    c.fill(Color::new(value, value, value));
+    c
+}
+
+fn mean_red(rgb: &[[u8; 3]]) -> f64 {
+    rgb.iter().map(|p| f64::from(p[0])).sum::<f64>() / rgb.len() as f64
+}
+
+#[test]
+fn quantizing_without_dithering_rounds_every_pixel() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(1.5, 0.5, -1.0));
+    c.write_pixel(1, 0, Color::new(0.1, 0.2, 0.3));
+
+    assert_eq!(vec![[255, 128, 0], [26, 51, 77]], c.to_rgb8(Dither::None));
+}
+
+#[test]
+fn exact_levels_are_not_dithered() {
+    let c = constant(16, 16, 51.0 / 255.0);
+
+    for dither in DITHERS {
+        assert!(c.to_rgb8(dither).iter().all(|&p| p == [51, 51, 51]));
+    }
+}
+
+#[test]
+fn black_and_white_are_not_dithered() {
+    for dither in DITHERS {
+        assert!(constant(9, 9, 0.0)
+            .to_rgb8(dither)
+            .iter()
+            .all(|&p| p == [0, 0, 0]));
+        assert!(constant(9, 9, 2.0)
+            .to_rgb8(dither)
+            .iter()
+            .all(|&p| p == [255, 255, 255]));
+    }
+}
+
+#[test]
+fn bayer_dithering_mixes_neighboring_levels_in_proportion() {
+    // a quarter of the way from 100 to 101
+    let rgb = constant(8, 8, 100.25 / 255.0).to_rgb8(Dither::Bayer);
+
+    assert_eq!(48, rgb.iter().filter(|p| p[0] == 100).count());
+    assert_eq!(16, rgb.iter().filter(|p| p[0] == 101).count());
+}
+
+#[test]
+fn blue_noise_dithering_mixes_neighboring_levels_in_proportion() {
+    let rgb = constant(32, 32, 100.5 / 255.0).to_rgb8(Dither::BlueNoise);
+
+    assert_eq!(100.5, mean_red(&rgb));
+    assert!(rgb.iter().all(|p| p[0] == 100 || p[0] == 101));
+}
+
+#[test]
+fn blue_noise_spreads_the_brighter_pixels_evenly() {
+    // at 1/16 coverage, no two of the brighter pixels should touch
+    let rgb = constant(32, 32, 100.0625 / 255.0).to_rgb8(Dither::BlueNoise);
+    let bright = |x: usize, y: usize| rgb[(y % 32) * 32 + x % 32][0] == 101;
+
+    assert_eq!(64, (0..1024).filter(|&i| bright(i % 32, i / 32)).count());
+    for y in 0..32 {
+        for x in 0..32 {
+            if bright(x, y) {
+                assert!(!bright(x + 1, y) && !bright(x, y + 1) && !bright(x + 1, y + 1));
+                assert!(!bright(x + 31, y + 1));
+            }
+        }
+    }
+}
+
+#[test]
+fn floyd_steinberg_dithering_preserves_the_average() {
+    let rgb = constant(64, 64, 0.3).to_rgb8(Dither::FloydSteinberg);
+
+    assert!((mean_red(&rgb) - 0.3 * 255.0).abs() < 0.05);
+    assert!(rgb.iter().all(|p| p[0] == 76 || p[0] == 77));
+}
+
+#[test]
+fn dithering_is_selected_per_export() {
+    let mut c: Canvas = Canvas::new(64, 1);
+    for x in 0..64 {
+        let value = 0.5 + x as f64 / 64.0 / 255.0;
+        c.write_pixel(x, 0, Color::new(value, value, value));
+    }
+    let options = ExportOptions {
+        dither: Dither::Bayer,
+        ..ExportOptions::default()
+    };
+
+    assert_eq!(c.to_ppm(), c.to_ppm_with(&ExportOptions::default()));
+    assert_ne!(c.to_ppm(), c.to_ppm_with(&options));
+    assert_ne!(c.to_png().unwrap(), c.to_png_with(&options).unwrap());
+
+    let read: Canvas = Canvas::from_png(&c.to_png_with(&options).unwrap()).unwrap();
+    let expected: Vec<[u8; 3]> = c.to_rgb8(Dither::Bayer);
+    assert_eq!(expected, read.to_rgb8(Dither::None));
+}
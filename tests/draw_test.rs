@@ -0,0 +1,260 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::tuples::Color;
+
+fn painted(c: &Canvas) -> Vec<(usize, usize)> {
+    let mut points = Vec::new();
+    for y in 0..c.height() {
+        for x in 0..c.width() {
+            if c.pixel_at(x, y) != Color::black() {
+                points.push((x, y));
+            }
+        }
+    }
+    points
+}
+
+#[test]
+fn blending_a_pixel() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.fill(Color::new(1.0, 0.0, 0.0));
+
+    c.blend_pixel(0, 0, Color::new(0.0, 0.0, 1.0), 0.25);
+    c.blend_pixel(1, 0, Color::new(0.0, 0.0, 1.0), 2.0);
+
+    assert_eq!(Color::new(0.75, 0.0, 0.25), c.pixel_at(0, 0));
+    assert_eq!(Color::new(0.0, 0.0, 1.0), c.pixel_at(1, 0));
+}
+
+#[test]
+fn blending_outside_of_the_canvas_is_ignored() {
+    let mut c: Canvas = Canvas::new(2, 2);
+
+    c.blend_pixel(-1, 0, Color::white(), 1.0);
+    c.blend_pixel(0, 2, Color::white(), 1.0);
+
+    assert!(painted(&c).is_empty());
+}
+
+#[test]
+fn drawing_a_line_with_bresenham() {
+    let mut c: Canvas = Canvas::new(6, 4);
+
+    c.draw_line((0, 0), (5, 2), Color::white(), 1.0);
+
+    assert_eq!(
+        vec![(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)],
+        painted(&c)
+    );
+}
+
+#[test]
+fn lines_are_the_same_in_both_directions_and_clipped() {
+    let mut forward: Canvas = Canvas::new(5, 5);
+    let mut backward: Canvas = Canvas::new(5, 5);
+
+    forward.draw_line((-2, 4), (6, 0), Color::white(), 1.0);
+    backward.draw_line((6, 0), (-2, 4), Color::white(), 1.0);
+
+    assert_eq!(forward, backward);
+    assert_eq!(5, painted(&forward).len());
+}
+
+#[test]
+fn lines_far_outside_of_the_canvas_are_clipped_before_rasterizing() {
+    let mut c: Canvas = Canvas::new(10, 10);
+
+    c.draw_line((0, 0), (200_000_000, 5), Color::white(), 1.0);
+    c.draw_line((0, 9), (isize::MAX / 2, 9), Color::white(), 1.0);
+    c.draw_line(
+        (isize::MIN, isize::MIN),
+        (isize::MAX, isize::MAX),
+        Color::white(),
+        1.0,
+    );
+
+    let mut expected: Vec<(usize, usize)> = (0..10).map(|x| (x, 0)).collect();
+    expected.extend((1..9).map(|i| (i, i)));
+    expected.extend((0..10).map(|x| (x, 9)));
+    assert_eq!(expected, painted(&c));
+}
+
+#[test]
+fn a_clipped_line_covers_the_pixels_of_the_whole_line() {
+    let mut clipped: Canvas = Canvas::new(10, 10);
+    let mut whole: Canvas = Canvas::new(100, 100);
+
+    for &(from, to) in &[
+        ((-30, -7), (40, 18)),
+        ((13, -25), (-4, 31)),
+        ((5, 12), (5, -3)),
+    ] {
+        let shifted = |(x, y): (isize, isize)| (x + 50, y + 50);
+        clipped.draw_line(from, to, Color::white(), 1.0);
+        whole.draw_line(shifted(from), shifted(to), Color::white(), 1.0);
+    }
+
+    assert_eq!(whole.crop(50, 50, 10, 10), clipped);
+}
+
+#[test]
+fn anti_aliased_lines_far_outside_of_the_canvas_are_clipped() {
+    let mut c: Canvas = Canvas::new(10, 10);
+
+    c.draw_line_aa((-1e12, 2.0), (1e12, 2.0), Color::white(), 1.0);
+    c.draw_line_aa((f64::NEG_INFINITY, 5.0), (3.0, 5.0), Color::white(), 1.0);
+    c.draw_line_aa((0.0, f64::NAN), (3.0, 5.0), Color::white(), 1.0);
+
+    let expected: Vec<(usize, usize)> = (0..10).map(|x| (x, 2)).collect();
+    assert_eq!(expected, painted(&c));
+}
+
+#[test]
+fn an_anti_aliased_line_splits_coverage_between_pixels() {
+    let mut c: Canvas = Canvas::new(5, 3);
+
+    c.draw_line_aa((0.0, 0.5), (4.0, 0.5), Color::white(), 1.0);
+
+    for x in 1..4 {
+        assert_eq!(Color::new(0.5, 0.5, 0.5), c.pixel_at(x, 0));
+        assert_eq!(Color::new(0.5, 0.5, 0.5), c.pixel_at(x, 1));
+        assert_eq!(Color::black(), c.pixel_at(x, 2));
+    }
+    // the end points cover half of their pixels horizontally
+    assert_eq!(Color::new(0.25, 0.25, 0.25), c.pixel_at(0, 0));
+    assert_eq!(Color::new(0.25, 0.25, 0.25), c.pixel_at(4, 1));
+}
+
+#[test]
+fn a_steep_anti_aliased_line_on_pixel_centers_is_solid() {
+    let mut c: Canvas = Canvas::new(3, 5);
+
+    c.draw_line_aa((1.0, 4.0), (1.0, 0.0), Color::white(), 1.0);
+
+    for y in 1..4 {
+        assert_eq!(Color::white(), c.pixel_at(1, y));
+        assert_eq!(Color::black(), c.pixel_at(0, y));
+        assert_eq!(Color::black(), c.pixel_at(2, y));
+    }
+}
+
+#[test]
+fn drawing_a_circle() {
+    let mut c: Canvas = Canvas::new(7, 7);
+
+    c.draw_circle((3, 3), 2, Color::white(), 1.0);
+
+    assert_eq!(
+        vec![
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (1, 2),
+            (5, 2),
+            (1, 3),
+            (5, 3),
+            (1, 4),
+            (5, 4),
+            (2, 5),
+            (3, 5),
+            (4, 5)
+        ],
+        painted(&c)
+    );
+}
+
+#[test]
+fn circle_points_are_blended_once() {
+    let mut c: Canvas = Canvas::new(9, 9);
+
+    c.draw_circle((4, 4), 3, Color::white(), 0.5);
+
+    assert!(painted(&c)
+        .iter()
+        .all(|&(x, y)| c.pixel_at(x, y) == Color::new(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn filling_a_rectangle_clips_to_the_canvas() {
+    let mut c: Canvas = Canvas::new(4, 4);
+
+    c.fill_rect((2, -1), 5, 3, Color::new(0.0, 1.0, 0.0), 1.0);
+
+    assert_eq!(vec![(2, 0), (3, 0), (2, 1), (3, 1)], painted(&c));
+}
+
+#[test]
+fn rectangles_far_outside_of_the_canvas_are_clipped() {
+    let mut c: Canvas = Canvas::new(4, 4);
+
+    c.fill_rect((isize::MAX, 0), 1, 1, Color::white(), 1.0);
+    c.fill_rect((0, isize::MIN), 1, usize::MAX, Color::white(), 0.5);
+    c.fill_rect((isize::MIN, 3), usize::MAX, 1, Color::white(), 0.5);
+
+    let mut expected: Vec<(usize, usize)> = (0..3).map(|y| (0, y)).collect();
+    expected.extend((0..4).map(|x| (x, 3)));
+    assert_eq!(expected, painted(&c));
+    assert_eq!(Color::new(0.75, 0.75, 0.75), c.pixel_at(0, 3));
+}
+
+#[test]
+fn a_clipped_circle_covers_the_pixels_of_the_whole_circle() {
+    let mut clipped: Canvas = Canvas::new(10, 10);
+    let mut whole: Canvas = Canvas::new(100, 100);
+
+    for &(center, radius) in &[((-3, 4), 8), ((5, 5), 7), ((12, -6), 15), ((4, 20), 11)] {
+        let shifted = (center.0 + 50, center.1 + 50);
+        clipped.draw_circle(center, radius, Color::white(), 0.5);
+        whole.draw_circle(shifted, radius, Color::white(), 0.5);
+    }
+
+    assert_eq!(whole.crop(50, 50, 10, 10), clipped);
+}
+
+#[test]
+fn huge_circles_only_visit_the_canvas() {
+    let mut c: Canvas = Canvas::new(10, 10);
+    let radius = isize::MAX / 2;
+
+    // the top of the circle is flat across the canvas
+    c.draw_circle((5, 5 + radius), radius, Color::white(), 0.5);
+
+    let expected: Vec<(usize, usize)> = (0..10).map(|x| (x, 5)).collect();
+    assert_eq!(expected, painted(&c));
+    assert!(expected
+        .iter()
+        .all(|&(x, y)| c.pixel_at(x, y) == Color::new(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn drawing_a_polyline_blends_shared_vertices_once() {
+    let mut c: Canvas = Canvas::new(5, 5);
+
+    c.draw_polyline(&[(0, 0), (4, 0), (4, 4)], Color::white(), 0.5);
+
+    assert_eq!(9, painted(&c).len());
+    assert_eq!(Color::new(0.5, 0.5, 0.5), c.pixel_at(4, 0));
+}
+
+#[test]
+fn polylines_far_outside_of_the_canvas_are_clipped() {
+    let mut c: Canvas = Canvas::new(4, 4);
+
+    c.draw_polyline(
+        &[(-1_000_000_000, 1), (1_000_000_000, 1), (2, -1_000_000_000)],
+        Color::white(),
+        0.5,
+    );
+
+    assert_eq!(vec![(0, 1), (1, 1), (2, 1), (3, 1)], painted(&c));
+    assert_eq!(Color::new(0.5, 0.5, 0.5), c.pixel_at(2, 1));
+}
+
+#[test]
+fn a_polyline_of_a_single_point_is_a_dot() {
+    let mut c: Canvas = Canvas::new(3, 3);
+
+    c.draw_polyline(&[(1, 1)], Color::white(), 1.0);
+    c.draw_polyline(&[], Color::white(), 1.0);
+
+    assert_eq!(vec![(1, 1)], painted(&c));
+}
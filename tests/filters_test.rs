@@ -0,0 +1,215 @@
+use ray_tracer_challenge::assert_approx_eq;
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::filters::Resampling;
+use ray_tracer_challenge::matrices::Matrix;
+use ray_tracer_challenge::tuples::Color;
+
+fn sum(c: &Canvas) -> Color {
+    c.pixels().iter().fold(Color::black(), |sum, &p| sum + p)
+}
+
+fn gradient(width: usize, height: usize) -> Canvas {
+    let mut c = Canvas::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            c.write_pixel(x, y, Color::new(x as f64, y as f64, 1.0));
+        }
+    }
+    c
+}
+
+#[test]
+fn blurring_a_constant_canvas_leaves_it_unchanged() {
+    let mut c: Canvas = Canvas::new(6, 4);
+    c.fill(Color::new(0.2, 0.4, 0.6));
+
+    assert_eq!(c, c.gaussian_blur(1.5));
+}
+
+#[test]
+fn blurring_spreads_a_point_symmetrically_and_keeps_its_energy() {
+    let mut c: Canvas = Canvas::new(15, 15);
+    c.write_pixel(7, 7, Color::new(1.0, 1.0, 1.0));
+    let blurred = c.gaussian_blur(1.0);
+
+    assert_eq!(Color::new(1.0, 1.0, 1.0), sum(&blurred));
+    assert!(blurred.pixel_at(7, 7).red() < 1.0);
+    assert_eq!(blurred.pixel_at(6, 7), blurred.pixel_at(8, 7));
+    assert_eq!(blurred.pixel_at(7, 6), blurred.pixel_at(6, 7));
+    assert!(blurred.pixel_at(6, 7).red() > blurred.pixel_at(5, 7).red());
+}
+
+#[test]
+fn a_zero_sigma_blur_is_the_identity() {
+    let c = gradient(3, 2);
+
+    assert_eq!(c, c.gaussian_blur(0.0));
+}
+
+#[test]
+fn huge_sigmas_blur_with_taps_bounded_by_the_canvas() {
+    let c = gradient(40, 3);
+    let blurred = c.gaussian_blur(1e12);
+
+    // the weights are all but equal, as with any sigma far above the size
+    assert_eq!(c.gaussian_blur(1e6), blurred);
+    for x in 0..40 {
+        let red = blurred.pixel_at(x, 1).red();
+        assert!((0.0..=39.0).contains(&red), "{} at {}", red, x);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot blur with a sigma of inf")]
+fn blurring_with_an_infinite_sigma() {
+    gradient(3, 2).gaussian_blur(f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "cannot blur with a sigma of NaN")]
+fn blurring_with_a_nan_sigma() {
+    gradient(3, 2).gaussian_blur(f64::NAN);
+}
+
+#[test]
+fn the_bright_pass_keeps_luminance_above_the_threshold() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(0.5, 0.5, 0.5));
+    c.write_pixel(1, 0, Color::new(4.0, 4.0, 4.0));
+    let bright = c.bright_pass(1.0);
+
+    assert_eq!(Color::black(), bright.pixel_at(0, 0));
+    assert_eq!(Color::new(3.0, 3.0, 3.0), bright.pixel_at(1, 0));
+}
+
+#[test]
+fn bloom_adds_glow_around_bright_pixels() {
+    let mut c: Canvas = Canvas::new(9, 9);
+    c.write_pixel(4, 4, Color::new(10.0, 10.0, 10.0));
+    let bloomed = c.bloom(1.0, 1.0, 0.5);
+
+    assert!(bloomed.pixel_at(3, 4).red() > 0.0);
+    assert!(bloomed.pixel_at(4, 4).red() > 10.0);
+    // half of the 9 units above the threshold are added back
+    assert_approx_eq!(14.5, sum(&bloomed).red(), epsilon = 0.0001);
+}
+
+#[test]
+fn the_vignette_darkens_the_corners() {
+    let mut c: Canvas = Canvas::new(10, 10);
+    c.fill(Color::white());
+    let vignetted = c.vignette(0.5);
+
+    let center = vignetted.pixel_at(5, 5).red();
+    let corner = vignetted.pixel_at(0, 0).red();
+    assert!(center > 0.99);
+    assert_approx_eq!(1.0 - 0.5 * 0.81, corner);
+    assert_eq!(vignetted.pixel_at(0, 0), vignetted.pixel_at(9, 9));
+}
+
+#[test]
+fn grading_colors_with_a_matrix() {
+    let mut c: Canvas = Canvas::new(1, 1);
+    c.write_pixel(0, 0, Color::new(0.2, 0.4, 0.6));
+    let swap_red_and_blue = Matrix::new([[0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]);
+    let grayscale = Matrix::new([[1.0 / 3.0; 3]; 3]);
+
+    assert_eq!(
+        Color::new(0.6, 0.4, 0.2),
+        c.color_graded(&swap_red_and_blue).pixel_at(0, 0)
+    );
+    assert_eq!(
+        Color::new(0.4, 0.4, 0.4),
+        c.color_graded(&grayscale).pixel_at(0, 0)
+    );
+    assert_eq!(c, c.color_graded(&Matrix::identity()));
+}
+
+#[test]
+fn cropping_a_canvas() {
+    let cropped = gradient(5, 4).crop(1, 2, 3, 2);
+
+    assert_eq!(3, cropped.width());
+    assert_eq!(2, cropped.height());
+    assert_eq!(Color::new(1.0, 2.0, 1.0), cropped.pixel_at(0, 0));
+    assert_eq!(Color::new(3.0, 3.0, 1.0), cropped.pixel_at(2, 1));
+}
+
+#[test]
+#[should_panic(expected = "crop of 3x2 at (3, 0) is outside of the 5x4 canvas")]
+fn cropping_outside_of_the_canvas() {
+    gradient(5, 4).crop(3, 0, 3, 2);
+}
+
+#[test]
+#[should_panic(expected = "is outside of the 5x4 canvas")]
+fn cropping_with_an_overflowing_size() {
+    gradient(5, 4).crop(2, 0, usize::MAX, 1);
+}
+
+#[test]
+fn resizing_to_the_same_size_is_the_identity() {
+    let c = gradient(5, 3);
+
+    assert_eq!(c, c.resize(5, 3, Resampling::Bilinear));
+    assert_eq!(c, c.resize(5, 3, Resampling::Lanczos3));
+}
+
+#[test]
+fn bilinear_upscaling_interpolates_between_pixels() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::black());
+    c.write_pixel(1, 0, Color::white());
+    let resized = c.resize(4, 1, Resampling::Bilinear);
+
+    assert_eq!(Color::black(), resized.pixel_at(0, 0));
+    assert_eq!(Color::new(0.25, 0.25, 0.25), resized.pixel_at(1, 0));
+    assert_eq!(Color::new(0.75, 0.75, 0.75), resized.pixel_at(2, 0));
+    assert_eq!(Color::white(), resized.pixel_at(3, 0));
+}
+
+#[test]
+fn bilinear_downscaling_by_two_averages_pairs() {
+    let resized = gradient(4, 4).resize(2, 2, Resampling::Bilinear);
+
+    assert_eq!(Color::new(0.5, 0.5, 1.0), resized.pixel_at(0, 0));
+    assert_eq!(Color::new(2.5, 2.5, 1.0), resized.pixel_at(1, 1));
+}
+
+#[test]
+fn lanczos_resampling_preserves_constant_regions() {
+    let mut c: Canvas = Canvas::new(7, 5);
+    c.fill(Color::new(0.3, 0.6, 0.9));
+
+    for (width, height) in [(3, 2), (20, 11), (7, 1)] {
+        let resized = c.resize(width, height, Resampling::Lanczos3);
+
+        assert_eq!(width, resized.width());
+        assert_eq!(height, resized.height());
+        assert!(resized
+            .pixels()
+            .iter()
+            .all(|&p| p == Color::new(0.3, 0.6, 0.9)));
+    }
+}
+
+#[test]
+fn lanczos_downscaling_keeps_the_mean() {
+    let c = gradient(16, 16);
+    let resized = c.resize(4, 4, Resampling::Lanczos3);
+
+    // the gradient is linear, so the symmetric kernel keeps its average
+    assert_approx_eq!(
+        sum(&c).red() / 256.0,
+        sum(&resized).red() / 16.0,
+        epsilon = 0.0001
+    );
+}
+
+#[test]
+fn resizing_to_nothing() {
+    let resized = gradient(3, 3).resize(0, 4, Resampling::Lanczos3);
+
+    assert_eq!(0, resized.width());
+    assert_eq!(4, resized.height());
+}
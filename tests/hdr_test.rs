@@ -0,0 +1,196 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::tuples::Color;
+
+const HEADER: &[u8] = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n";
+
+#[test]
+fn writing_an_hdr_file_run_length_encodes_scanlines() {
+    let mut c: Canvas = Canvas::new(10, 1);
+    c.fill(Color::new(1.0, 1.0, 1.0));
+    let hdr = c.to_hdr();
+
+    let mut expected = HEADER.to_vec();
+    expected.extend_from_slice(b"-Y 1 +X 10\n");
+    expected.extend_from_slice(&[2, 2, 0, 10]);
+    expected.extend_from_slice(&[138, 128, 138, 128, 138, 128, 138, 129]);
+    assert_eq!(expected, hdr);
+}
+
+#[test]
+fn narrow_scanlines_are_written_flat() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(0.5, 0.25, 0.0));
+    c.write_pixel(1, 0, Color::new(3.0, 0.0, 0.0));
+    let hdr = c.to_hdr();
+
+    let raster = &hdr[hdr.len() - 8..];
+    assert_eq!(&[128, 64, 0, 128, 192, 0, 0, 130], raster);
+}
+
+#[test]
+fn a_canvas_survives_an_hdr_round_trip() {
+    let mut c: Canvas = Canvas::new(20, 3);
+    for y in 0..3 {
+        for x in 0..20 {
+            // the components share an exponent, so they are chosen to need
+            // at most 8 bits of mantissa relative to the largest one
+            let color = Color::new(x as f64 * 0.25, (x % 3) as f64 * 0.5, y as f64 * 0.25);
+            c.write_pixel(x, y, color);
+        }
+    }
+
+    let read: Canvas = Canvas::from_hdr(&c.to_hdr()).unwrap();
+    for (expected, actual) in c.pixels().iter().zip(read.pixels()) {
+        assert_within_half_a_step(expected, actual);
+    }
+}
+
+#[test]
+fn hdr_values_decode_to_the_middle_of_their_step() {
+    let mut c: Canvas = Canvas::new(3, 1);
+    c.write_pixel(0, 0, Color::new(0.3, 0.1, 0.7));
+    c.write_pixel(1, 0, Color::new(0.1, 0.0, 0.0));
+    c.write_pixel(2, 0, Color::new(0.7, 0.3, 0.0));
+    let read: Canvas = Canvas::from_hdr(&c.to_hdr()).unwrap();
+
+    for (expected, actual) in c.pixels().iter().zip(read.pixels()) {
+        assert_within_half_a_step(expected, actual);
+    }
+    assert_eq!(0.0, read.pixel_at(1, 0).green());
+    assert_eq!(0.0, read.pixel_at(2, 0).blue());
+}
+
+// the components share the exponent of the largest one, whose 8-bit mantissa
+// is at least 128, so half a step is at most 1/256 of the largest component
+fn assert_within_half_a_step(expected: &Color, actual: &Color) {
+    let largest = expected.red().max(expected.green()).max(expected.blue());
+    let half_step = largest / 256.0;
+
+    for (e, a) in [
+        (expected.red(), actual.red()),
+        (expected.green(), actual.green()),
+        (expected.blue(), actual.blue()),
+    ] {
+        assert!(
+            (e - a).abs() <= half_step,
+            "{:?} decoded as {:?}",
+            expected,
+            actual
+        );
+    }
+}
+
+#[test]
+fn hdr_colors_keep_eight_bits_of_precision() {
+    let mut c: Canvas = Canvas::new(1, 1);
+    c.write_pixel(0, 0, Color::new(12345.678, 0.001, -1.0));
+    let read: Canvas = Canvas::from_hdr(&c.to_hdr()).unwrap();
+    let color = read.pixel_at(0, 0);
+
+    assert!((color.red() - 12345.678).abs() / 12345.678 < 1.0 / 128.0);
+    assert_eq!(0.0, color.green());
+    assert_eq!(0.0, color.blue());
+}
+
+#[test]
+fn reading_an_hdr_file_with_old_style_run_lengths() {
+    let mut hdr = b"#?RGBE\nEXPOSURE=1.0\n\n-Y 1 +X 6\n".to_vec();
+    hdr.extend_from_slice(&[128, 0, 0, 129, 1, 1, 1, 3, 0, 0, 0, 0, 0, 128, 0, 129]);
+    let c: Canvas = Canvas::from_hdr(&hdr).unwrap();
+
+    // mantissas of 128 decode to the middle of their step
+    let one = 128.5 / 128.0;
+    for x in 0..4 {
+        assert_eq!(Color::new(one, 0.0, 0.0), c.pixel_at(x, 0));
+    }
+    assert_eq!(Color::black(), c.pixel_at(4, 0));
+    assert_eq!(Color::new(0.0, one, 0.0), c.pixel_at(5, 0));
+}
+
+#[test]
+fn reading_invalid_hdr_files_fails() {
+    let malformed = |reason: &str| Error::MalformedImage {
+        format: "Radiance HDR",
+        reason: reason.to_string(),
+    };
+
+    let mut truncated = HEADER.to_vec();
+    truncated.extend_from_slice(b"-Y 1 +X 8\n");
+    truncated.extend_from_slice(&[2, 2, 0, 8, 136]);
+
+    let mut overflow = HEADER.to_vec();
+    overflow.extend_from_slice(b"-Y 1 +X 8\n");
+    // padded past the smallest possible scanline, so the run is reached
+    overflow.extend_from_slice(&[2, 2, 0, 8, 137, 0, 0, 0]);
+
+    assert_eq!(
+        Err(malformed("missing #? signature")),
+        Canvas::<f64>::from_hdr(b"PF\n1 1\n-1.0\n")
+    );
+    assert_eq!(
+        Err(malformed("unsupported pixel format \"32-bit_rle_xyze\"")),
+        Canvas::<f64>::from_hdr(b"#?RADIANCE\nFORMAT=32-bit_rle_xyze\n\n-Y 1 +X 1\n")
+    );
+    assert_eq!(
+        Err(malformed("unsupported resolution \"+X 1 -Y 1\"")),
+        Canvas::<f64>::from_hdr(b"#?RADIANCE\n\n+X 1 -Y 1\n")
+    );
+    assert_eq!(
+        Err(Error::TruncatedImage {
+            format: "Radiance HDR"
+        }),
+        Canvas::<f64>::from_hdr(&truncated)
+    );
+    assert_eq!(
+        Err(malformed("run overflows the scanline")),
+        Canvas::<f64>::from_hdr(&overflow)
+    );
+}
+
+#[test]
+fn hdr_resolutions_larger_than_the_data_are_truncated() {
+    let truncated = Err(Error::TruncatedImage {
+        format: "Radiance HDR",
+    });
+
+    for resolution in ["-Y 1 +X 4611686018427387904", "-Y 1 +X 100000000000"] {
+        let mut hdr = HEADER.to_vec();
+        hdr.extend_from_slice(resolution.as_bytes());
+        hdr.extend_from_slice(b"\n");
+        hdr.extend_from_slice(&[128, 0, 0, 129, 1, 1, 1, 255]);
+
+        assert_eq!(truncated, Canvas::<f64>::from_hdr(&hdr), "{}", resolution);
+    }
+
+    // an image without rows needs no data, however wide it is
+    let empty: Canvas = Canvas::from_hdr(b"#?RADIANCE\n\n-Y 0 +X 1000000000000\n").unwrap();
+    assert_eq!((1_000_000_000_000, 0), (empty.width(), empty.height()));
+    assert!(empty.pixels().is_empty());
+
+    // old-style repeats claim the whole scanline in a few bytes
+    let mut repeated = b"#?RADIANCE\n\n-Y 1 +X 100000000000\n".to_vec();
+    repeated.extend_from_slice(&[128, 0, 0, 129]);
+    for _ in 0..5 {
+        repeated.extend_from_slice(&[1, 1, 1, 255]);
+    }
+    assert_eq!(57, repeated.len());
+    assert_eq!(
+        Err(Error::MalformedImage {
+            format: "Radiance HDR",
+            reason: "100000000000x1 is too large".to_string(),
+        }),
+        Canvas::<f64>::from_hdr(&repeated)
+    );
+}
+
+#[test]
+fn hdr_images_without_columns_are_empty() {
+    let mut hdr = HEADER.to_vec();
+    hdr.extend_from_slice(b"-Y 18446744073709551615 +X 0\n");
+    let c: Canvas = Canvas::from_hdr(&hdr).unwrap();
+
+    assert_eq!(0, c.width());
+    assert_eq!(usize::MAX, c.height());
+    assert!(c.pixels().is_empty());
+}
@@ -0,0 +1,51 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::image::ImageFormat;
+use ray_tracer_challenge::tuples::Color;
+
+#[test]
+fn choosing_a_format_from_the_file_extension() {
+    assert_eq!(Some(ImageFormat::Png), ImageFormat::from_path("render.png"));
+    assert_eq!(
+        Some(ImageFormat::Hdr),
+        ImageFormat::from_path("out/sky.HDR")
+    );
+    assert_eq!(Some(ImageFormat::Pfm), ImageFormat::from_path("a.b.pfm"));
+    assert_eq!(Some(ImageFormat::Ppm), ImageFormat::from_path("image.ppm"));
+    assert_eq!(None, ImageFormat::from_path("image.jpg"));
+    assert_eq!(None, ImageFormat::from_path("image"));
+}
+
+#[test]
+fn decoding_detects_every_format() {
+    let mut c: Canvas = Canvas::new(3, 2);
+    c.write_pixel(0, 0, Color::new(1.0, 0.0, 0.0));
+    c.write_pixel(2, 1, Color::new(0.0, 0.2, 1.0));
+
+    for format in [
+        ImageFormat::Ppm,
+        ImageFormat::Png,
+        ImageFormat::Pfm,
+        ImageFormat::Hdr,
+    ] {
+        let bytes = c.encode(format).unwrap();
+
+        assert_eq!(Some(format), ImageFormat::detect(&bytes));
+        // compared at 8 bits, since hdr decodes to the middle of its steps
+        assert_eq!(
+            c.pixel_at(0, 0).to_rgb8(),
+            Canvas::<f64>::decode(&bytes)
+                .unwrap()
+                .pixel_at(0, 0)
+                .to_rgb8()
+        );
+    }
+}
+
+#[test]
+fn decoding_an_unknown_format_fails() {
+    assert_eq!(
+        Some(Error::UnknownImageFormat),
+        Canvas::<f64>::decode(b"GIF89a").err()
+    );
+}
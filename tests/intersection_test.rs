@@ -0,0 +1,152 @@
+use ray_tracer_challenge::intersections::{Intersection, Intersections, Normal};
+use ray_tracer_challenge::rays::Ray;
+use ray_tracer_challenge::scalar::Scalar;
+use ray_tracer_challenge::tuples::{Point, Vector};
+
+// a unit sphere at the origin, standing in for a real shape
+#[derive(Debug)]
+struct Ball;
+
+impl Normal for Ball {
+    fn normal_at(&self, world_point: &Point) -> Vector {
+        (*world_point - Point::new(0.0, 0.0, 0.0)).normalize()
+    }
+}
+
+#[test]
+fn an_intersection_encapsulates_t_and_object() {
+    let ball = Ball;
+    let i = Intersection::new(3.5, &ball);
+
+    assert_eq!(3.5, i.t);
+    assert!(std::ptr::eq(&ball, i.object));
+}
+
+#[test]
+fn aggregating_intersections() {
+    let ball = Ball;
+    let xs = Intersections::new(vec![
+        Intersection::new(1.0, &ball),
+        Intersection::new(2.0, &ball),
+    ]);
+
+    assert_eq!(2, xs.len());
+    assert_eq!(1.0, xs[0].t);
+    assert_eq!(2.0, xs[1].t);
+}
+
+#[test]
+fn intersections_are_kept_sorted() {
+    let ball = Ball;
+    let mut xs: Intersections<Ball> = [5.0, -3.0, 2.0]
+        .iter()
+        .map(|&t| Intersection::new(t, &ball))
+        .collect();
+    xs.push(Intersection::new(0.5, &ball));
+    xs.extend(Intersections::new(vec![Intersection::new(7.0, &ball)]));
+
+    let ts: Vec<f64> = xs.iter().map(|i| i.t).collect();
+    assert_eq!(vec![-3.0, 0.5, 2.0, 5.0, 7.0], ts);
+}
+
+#[test]
+fn the_hit_when_all_intersections_have_positive_t() {
+    let ball = Ball;
+    let i1 = Intersection::new(1.0, &ball);
+    let i2 = Intersection::new(2.0, &ball);
+    let xs = Intersections::new(vec![i2, i1]);
+
+    assert_eq!(Some(&i1), xs.hit());
+}
+
+#[test]
+fn the_hit_when_some_intersections_have_negative_t() {
+    let ball = Ball;
+    let i1 = Intersection::new(-1.0, &ball);
+    let i2 = Intersection::new(1.0, &ball);
+    let xs = Intersections::new(vec![i2, i1]);
+
+    assert_eq!(Some(&i2), xs.hit());
+}
+
+#[test]
+fn the_hit_when_all_intersections_have_negative_t() {
+    let ball = Ball;
+    let xs = Intersections::new(vec![
+        Intersection::new(-2.0, &ball),
+        Intersection::new(-1.0, &ball),
+    ]);
+
+    assert_eq!(None, xs.hit());
+}
+
+#[test]
+fn the_hit_is_always_the_lowest_nonnegative_intersection() {
+    let ball = Ball;
+    let i4 = Intersection::new(2.0, &ball);
+    let xs = Intersections::new(vec![
+        Intersection::new(5.0, &ball),
+        Intersection::new(7.0, &ball),
+        Intersection::new(-3.0, &ball),
+        i4,
+    ]);
+
+    assert_eq!(Some(&i4), xs.hit());
+}
+
+#[test]
+fn a_hit_at_zero_counts() {
+    let ball = Ball;
+    let xs = Intersections::new(vec![Intersection::new(0.0, &ball)]);
+
+    assert_eq!(Some(0.0), xs.hit().map(|i| i.t));
+}
+
+#[test]
+fn precomputing_the_state_of_an_intersection() {
+    let ball = Ball;
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let comps = Intersection::new(4.0, &ball).prepare_computations(&r);
+
+    assert_eq!(4.0, comps.t);
+    assert!(std::ptr::eq(&ball, comps.object));
+    assert_eq!(Point::new(0.0, 0.0, -1.0), comps.point);
+    assert_eq!(Vector::new(0.0, 0.0, -1.0), comps.eyev);
+    assert_eq!(Vector::new(0.0, 0.0, -1.0), comps.normalv);
+}
+
+#[test]
+fn the_hit_when_an_intersection_occurs_on_the_outside() {
+    let ball = Ball;
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let comps = Intersection::new(4.0, &ball).prepare_computations(&r);
+
+    assert!(!comps.inside);
+}
+
+#[test]
+fn the_hit_when_an_intersection_occurs_on_the_inside() {
+    let ball = Ball;
+    let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    let comps = Intersection::new(1.0, &ball).prepare_computations(&r);
+
+    assert_eq!(Point::new(0.0, 0.0, 1.0), comps.point);
+    assert_eq!(Vector::new(0.0, 0.0, -1.0), comps.eyev);
+    assert!(comps.inside);
+    // the normal is flipped to face the eye
+    assert_eq!(Vector::new(0.0, 0.0, -1.0), comps.normalv);
+}
+
+#[test]
+fn the_over_and_under_points_are_offset_along_the_normal() {
+    let ball = Ball;
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let comps = Intersection::new(4.0, &ball).prepare_computations(&r);
+
+    let epsilon = <f64 as Scalar>::EPSILON;
+
+    assert!(comps.over_point.z() < -1.0 - epsilon / 2.0);
+    assert!(comps.under_point.z() > -1.0 + epsilon / 2.0);
+    assert!(comps.point.z() > comps.over_point.z());
+    assert!(comps.point.z() < comps.under_point.z());
+}
@@ -0,0 +1,392 @@
+// the reference variants of the operators are exercised on purpose
+#![allow(clippy::op_ref)]
+
+use ray_tracer_challenge::assert_approx_eq;
+use ray_tracer_challenge::matrices::Matrix;
+use ray_tracer_challenge::tuples::{Point, Tuple, Vector};
+
+#[test]
+fn constructing_and_inspecting_a_4x4_matrix() {
+    let m = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.5, 6.5, 7.5, 8.5],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.5, 14.5, 15.5, 16.5],
+    ]);
+
+    assert_eq!(1.0, m[(0, 0)]);
+    assert_eq!(4.0, m[(0, 3)]);
+    assert_eq!(5.5, m[(1, 0)]);
+    assert_eq!(7.5, m[(1, 2)]);
+    assert_eq!(11.0, m[(2, 2)]);
+    assert_eq!(13.5, m[(3, 0)]);
+    assert_eq!(15.5, m[(3, 2)]);
+}
+
+#[test]
+fn a_2x2_matrix_ought_to_be_representable() {
+    let m = Matrix::new([[-3.0, 5.0], [1.0, -2.0]]);
+
+    assert_eq!(-3.0, m[(0, 0)]);
+    assert_eq!(5.0, m[(0, 1)]);
+    assert_eq!(1.0, m[(1, 0)]);
+    assert_eq!(-2.0, m[(1, 1)]);
+}
+
+#[test]
+fn a_3x3_matrix_ought_to_be_representable() {
+    let m = Matrix::new([[-3.0, 5.0, 0.0], [1.0, -2.0, -7.0], [0.0, 1.0, 1.0]]);
+
+    assert_eq!(-3.0, m[(0, 0)]);
+    assert_eq!(-2.0, m[(1, 1)]);
+    assert_eq!(1.0, m[(2, 2)]);
+}
+
+#[test]
+fn writing_an_element_of_a_matrix() {
+    let mut m: Matrix<2> = Matrix::identity();
+    m[(0, 1)] = 3.0;
+
+    assert_eq!(Matrix::new([[1.0, 3.0], [0.0, 1.0]]), m);
+}
+
+#[test]
+fn matrix_equality_with_identical_matrices() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 8.0, 7.0, 6.0],
+        [5.0, 4.0, 3.0, 2.0],
+    ]);
+    let b = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 8.0, 7.0, 6.0],
+        [5.0, 4.0, 3.0, 2.0],
+    ]);
+
+    assert_eq!(a, b);
+}
+
+#[test]
+fn matrix_equality_with_different_matrices() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 8.0, 7.0, 6.0],
+        [5.0, 4.0, 3.0, 2.0],
+    ]);
+    let b = Matrix::new([
+        [2.0, 3.0, 4.0, 5.0],
+        [6.0, 7.0, 8.0, 9.0],
+        [8.0, 7.0, 6.0, 5.0],
+        [4.0, 3.0, 2.0, 1.0],
+    ]);
+
+    assert_ne!(a, b);
+}
+
+#[test]
+fn multiplying_two_matrices() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 8.0, 7.0, 6.0],
+        [5.0, 4.0, 3.0, 2.0],
+    ]);
+    let b = Matrix::new([
+        [-2.0, 1.0, 2.0, 3.0],
+        [3.0, 2.0, 1.0, -1.0],
+        [4.0, 3.0, 6.0, 5.0],
+        [1.0, 2.0, 7.0, 8.0],
+    ]);
+
+    let result = Matrix::new([
+        [20.0, 22.0, 50.0, 48.0],
+        [44.0, 54.0, 114.0, 108.0],
+        [40.0, 58.0, 110.0, 102.0],
+        [16.0, 26.0, 46.0, 42.0],
+    ]);
+
+    assert_eq!(result, a * b);
+    assert_eq!(result, &a * &b);
+    assert_eq!(result, a * &b);
+    assert_eq!(result, &a * b);
+}
+
+#[test]
+fn a_matrix_multiplied_by_a_tuple() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [2.0, 4.0, 4.0, 2.0],
+        [8.0, 6.0, 4.0, 1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let b = Tuple::new(1.0, 2.0, 3.0, 1.0);
+
+    assert_eq!(Tuple::new(18.0, 24.0, 33.0, 1.0), a * b);
+    assert_eq!(Tuple::new(18.0, 24.0, 33.0, 1.0), &a * &b);
+}
+
+#[test]
+fn a_matrix_multiplied_by_a_point_and_a_vector() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0, 4.0],
+        [2.0, 4.0, 4.0, 2.0],
+        [8.0, 6.0, 4.0, 1.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    let p = Point::new(1.0, 2.0, 3.0);
+    let v = Vector::new(1.0, 2.0, 3.0);
+
+    assert_eq!(Point::new(18.0, 24.0, 33.0), a * p);
+    assert_eq!(Vector::new(14.0, 22.0, 32.0), a * v);
+    assert_eq!(Point::new(18.0, 24.0, 33.0), &a * &p);
+    assert_eq!(Vector::new(14.0, 22.0, 32.0), &a * &v);
+}
+
+#[test]
+fn multiplying_a_matrix_by_the_identity_matrix() {
+    let a = Matrix::new([
+        [0.0, 1.0, 2.0, 4.0],
+        [1.0, 2.0, 4.0, 8.0],
+        [2.0, 4.0, 8.0, 16.0],
+        [4.0, 8.0, 16.0, 32.0],
+    ]);
+
+    assert_eq!(a, a * Matrix::identity());
+}
+
+#[test]
+fn multiplying_the_identity_matrix_by_a_tuple() {
+    let a = Tuple::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(a, Matrix::identity() * a);
+}
+
+#[test]
+fn transposing_a_matrix() {
+    let a = Matrix::new([
+        [0.0, 9.0, 3.0, 0.0],
+        [9.0, 8.0, 0.0, 8.0],
+        [1.0, 8.0, 5.0, 3.0],
+        [0.0, 0.0, 5.0, 8.0],
+    ]);
+
+    let result = Matrix::new([
+        [0.0, 9.0, 1.0, 0.0],
+        [9.0, 8.0, 8.0, 0.0],
+        [3.0, 0.0, 5.0, 5.0],
+        [0.0, 8.0, 3.0, 8.0],
+    ]);
+
+    assert_eq!(result, a.transpose());
+}
+
+#[test]
+fn transposing_the_identity_matrix() {
+    let a: Matrix<4> = Matrix::identity();
+
+    assert_eq!(Matrix::identity(), a.transpose());
+}
+
+#[test]
+fn calculating_the_determinant_of_a_2x2_matrix() {
+    let a = Matrix::new([[1.0, 5.0], [-3.0, 2.0]]);
+
+    assert_eq!(17.0, a.determinant());
+}
+
+#[test]
+fn a_submatrix_of_a_3x3_matrix_is_a_2x2_matrix() {
+    let a = Matrix::new([[1.0, 5.0, 0.0], [-3.0, 2.0, 7.0], [0.0, 6.0, -3.0]]);
+
+    assert_eq!(Matrix::new([[-3.0, 2.0], [0.0, 6.0]]), a.submatrix(0, 2));
+}
+
+#[test]
+fn a_submatrix_of_a_4x4_matrix_is_a_3x3_matrix() {
+    let a = Matrix::new([
+        [-6.0, 1.0, 1.0, 6.0],
+        [-8.0, 5.0, 8.0, 6.0],
+        [-1.0, 0.0, 8.0, 2.0],
+        [-7.0, 1.0, -1.0, 1.0],
+    ]);
+
+    let result = Matrix::new([[-6.0, 1.0, 6.0], [-8.0, 8.0, 6.0], [-7.0, -1.0, 1.0]]);
+
+    assert_eq!(result, a.submatrix(2, 1));
+}
+
+#[test]
+fn calculating_a_minor_of_a_3x3_matrix() {
+    let a = Matrix::new([[3.0, 5.0, 0.0], [2.0, -1.0, -7.0], [6.0, -1.0, 5.0]]);
+
+    assert_eq!(25.0, a.submatrix(1, 0).determinant());
+    assert_eq!(25.0, a.minor(1, 0));
+}
+
+#[test]
+fn calculating_a_cofactor_of_a_3x3_matrix() {
+    let a = Matrix::new([[3.0, 5.0, 0.0], [2.0, -1.0, -7.0], [6.0, -1.0, 5.0]]);
+
+    assert_eq!(-12.0, a.minor(0, 0));
+    assert_eq!(-12.0, a.cofactor(0, 0));
+    assert_eq!(25.0, a.minor(1, 0));
+    assert_eq!(-25.0, a.cofactor(1, 0));
+}
+
+#[test]
+fn calculating_the_determinant_of_a_3x3_matrix() {
+    let a = Matrix::new([[1.0, 2.0, 6.0], [-5.0, 8.0, -4.0], [2.0, 6.0, 4.0]]);
+
+    assert_eq!(56.0, a.cofactor(0, 0));
+    assert_eq!(12.0, a.cofactor(0, 1));
+    assert_eq!(-46.0, a.cofactor(0, 2));
+    assert_eq!(-196.0, a.determinant());
+}
+
+#[test]
+fn calculating_the_determinant_of_a_4x4_matrix() {
+    let a = Matrix::new([
+        [-2.0, -8.0, 3.0, 5.0],
+        [-3.0, 1.0, 7.0, 3.0],
+        [1.0, 2.0, -9.0, 6.0],
+        [-6.0, 7.0, 7.0, -9.0],
+    ]);
+
+    assert_eq!(690.0, a.cofactor(0, 0));
+    assert_eq!(447.0, a.cofactor(0, 1));
+    assert_eq!(210.0, a.cofactor(0, 2));
+    assert_eq!(51.0, a.cofactor(0, 3));
+    assert_eq!(-4071.0, a.determinant());
+}
+
+#[test]
+fn testing_an_invertible_matrix_for_invertibility() {
+    let a = Matrix::new([
+        [6.0, 4.0, 4.0, 4.0],
+        [5.0, 5.0, 7.0, 6.0],
+        [4.0, -9.0, 3.0, -7.0],
+        [9.0, 1.0, 7.0, -6.0],
+    ]);
+
+    assert_eq!(-2120.0, a.determinant());
+    assert!(a.is_invertible());
+}
+
+#[test]
+fn testing_a_noninvertible_matrix_for_invertibility() {
+    let a = Matrix::new([
+        [-4.0, 2.0, -2.0, -3.0],
+        [9.0, 6.0, 2.0, 6.0],
+        [0.0, -5.0, 1.0, -5.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ]);
+
+    assert_eq!(0.0, a.determinant());
+    assert!(!a.is_invertible());
+    assert_eq!(None, a.inverse());
+}
+
+#[test]
+fn calculating_the_inverse_of_a_matrix() {
+    let a = Matrix::new([
+        [-5.0, 2.0, 6.0, -8.0],
+        [1.0, -5.0, 1.0, 8.0],
+        [7.0, 7.0, -6.0, -7.0],
+        [1.0, -3.0, 7.0, 4.0],
+    ]);
+    let b = a.inverse().unwrap();
+
+    let result = Matrix::new([
+        [0.21805, 0.45113, 0.24060, -0.04511],
+        [-0.80827, -1.45677, -0.44361, 0.52068],
+        [-0.07895, -0.22368, -0.05263, 0.19737],
+        [-0.52256, -0.81391, -0.30075, 0.30639],
+    ]);
+
+    assert_eq!(532.0, a.determinant());
+    assert_eq!(-160.0, a.cofactor(2, 3));
+    assert_eq!(-160.0 / 532.0, b[(3, 2)]);
+    assert_eq!(105.0, a.cofactor(3, 2));
+    assert_eq!(105.0 / 532.0, b[(2, 3)]);
+    assert_eq!(result, b);
+}
+
+#[test]
+fn calculating_the_inverse_of_another_matrix() {
+    let a = Matrix::new([
+        [8.0, -5.0, 9.0, 2.0],
+        [7.0, 5.0, 6.0, 1.0],
+        [-6.0, 0.0, 9.0, 6.0],
+        [-3.0, 0.0, -9.0, -4.0],
+    ]);
+
+    let result = Matrix::new([
+        [-0.15385, -0.15385, -0.28205, -0.53846],
+        [-0.07692, 0.12308, 0.02564, 0.03077],
+        [0.35897, 0.35897, 0.43590, 0.92308],
+        [-0.69231, -0.69231, -0.76923, -1.92308],
+    ]);
+
+    assert_eq!(Some(result), a.inverse());
+}
+
+#[test]
+fn calculating_the_inverse_of_a_third_matrix() {
+    let a = Matrix::new([
+        [9.0, 3.0, 0.0, 9.0],
+        [-5.0, -2.0, -6.0, -3.0],
+        [-4.0, 9.0, 6.0, 4.0],
+        [-7.0, 6.0, 6.0, 2.0],
+    ]);
+
+    let result = Matrix::new([
+        [-0.04074, -0.07778, 0.14444, -0.22222],
+        [-0.07778, 0.03333, 0.36667, -0.33333],
+        [-0.02901, -0.14630, -0.10926, 0.12963],
+        [0.17778, 0.06667, -0.26667, 0.33333],
+    ]);
+
+    assert_eq!(Some(result), a.inverse());
+}
+
+#[test]
+fn calculating_the_inverse_of_smaller_matrices() {
+    let a = Matrix::new([[4.0, 7.0], [2.0, 6.0]]);
+    let b = Matrix::new([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+
+    let result_a = Matrix::new([[0.6, -0.7], [-0.2, 0.4]]);
+    let result_b = Matrix::new([[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]]);
+
+    assert_eq!(Some(result_a), a.inverse());
+    assert_eq!(Some(result_b), b.inverse());
+}
+
+#[test]
+fn multiplying_a_product_by_its_inverse() {
+    let a = Matrix::new([
+        [3.0, -9.0, 7.0, 3.0],
+        [3.0, -8.0, 2.0, -9.0],
+        [-4.0, 4.0, 4.0, 1.0],
+        [-6.0, 5.0, -1.0, 1.0],
+    ]);
+    let b = Matrix::new([
+        [8.0, 2.0, 2.0, 2.0],
+        [3.0, -1.0, 7.0, 0.0],
+        [7.0, 0.0, 5.0, 4.0],
+        [6.0, -2.0, 0.0, 5.0],
+    ]);
+    let c = a * b;
+
+    assert_approx_eq!(a, c * b.inverse().unwrap());
+}
+
+#[test]
+fn working_with_single_precision_matrices() {
+    let a: Matrix<3, f32> = Matrix::new([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+
+    assert_eq!(1.0, a.determinant());
+    assert_approx_eq!(Matrix::identity(), a * a.inverse().unwrap());
+}
@@ -0,0 +1,61 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::tuples::Color;
+
+#[test]
+fn writing_a_pfm_file_stores_scanlines_bottom_to_top() {
+    let mut c: Canvas = Canvas::new(1, 2);
+    c.write_pixel(0, 0, Color::new(1.0, 2.0, 3.0));
+    c.write_pixel(0, 1, Color::new(-0.5, 0.0, 100.0));
+    let pfm = c.to_pfm();
+
+    let header = b"PF\n1 2\n-1.0\n";
+    assert_eq!(header.to_vec(), pfm[..header.len()].to_vec());
+
+    let values: Vec<f32> = pfm[header.len()..]
+        .chunks(4)
+        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect();
+    assert_eq!(vec![-0.5, 0.0, 100.0, 1.0, 2.0, 3.0], values);
+}
+
+#[test]
+fn a_canvas_survives_a_pfm_round_trip_without_clamping() {
+    let mut c: Canvas = Canvas::new(3, 2);
+    c.write_pixel(0, 0, Color::new(1.5, 0.25, 1000.0));
+    c.write_pixel(2, 1, Color::new(-2.0, 0.125, 65504.0));
+
+    assert_eq!(c, Canvas::from_pfm(&c.to_pfm()).unwrap());
+}
+
+#[test]
+fn reading_a_big_endian_grayscale_pfm_file() {
+    let mut pfm = b"Pf\n2 1\n1.0\n".to_vec();
+    pfm.extend_from_slice(&0.5f32.to_be_bytes());
+    pfm.extend_from_slice(&4.0f32.to_be_bytes());
+    let c: Canvas = Canvas::from_pfm(&pfm).unwrap();
+
+    assert_eq!(Color::new(0.5, 0.5, 0.5), c.pixel_at(0, 0));
+    assert_eq!(Color::new(4.0, 4.0, 4.0), c.pixel_at(1, 0));
+}
+
+#[test]
+fn reading_invalid_pfm_files_fails() {
+    let malformed = |reason: &str| Error::MalformedImage {
+        format: "PFM",
+        reason: reason.to_string(),
+    };
+
+    assert_eq!(
+        Err(malformed("unknown magic number \"P6\"")),
+        Canvas::<f64>::from_pfm(b"P6\n1 1\n255\n\0\0\0")
+    );
+    assert_eq!(
+        Err(malformed("expected scale, found \"0\"")),
+        Canvas::<f64>::from_pfm(b"PF\n1 1\n0\n")
+    );
+    assert_eq!(
+        Err(Error::TruncatedImage { format: "PFM" }),
+        Canvas::<f64>::from_pfm(b"PF\n1 1\n-1.0\n\0\0\0\0")
+    );
+}
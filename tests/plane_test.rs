@@ -0,0 +1,71 @@
+use ray_tracer_challenge::intersections::Normal;
+use ray_tracer_challenge::planes::Plane;
+use ray_tracer_challenge::rays::Ray;
+use ray_tracer_challenge::shapes::{Object, Shape};
+use ray_tracer_challenge::transformations::{rotation_x, translation};
+use ray_tracer_challenge::tuples::{Point, Vector};
+use std::f64::consts::PI;
+
+#[test]
+fn the_normal_of_a_plane_is_constant_everywhere() {
+    let p = Plane;
+
+    for point in [
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(10.0, 0.0, -10.0),
+        Point::new(-5.0, 0.0, 150.0),
+    ] {
+        assert_eq!(Vector::new(0.0, 1.0, 0.0), p.local_normal_at(&point));
+    }
+}
+
+#[test]
+fn intersect_with_a_ray_parallel_to_the_plane() {
+    let r = Ray::new(Point::new(0.0, 10.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+    assert!(Plane.local_intersect(&r).is_empty());
+}
+
+#[test]
+fn intersect_with_a_coplanar_ray() {
+    let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+    assert!(Plane.local_intersect(&r).is_empty());
+}
+
+#[test]
+fn a_ray_intersecting_a_plane_from_above() {
+    let r = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+
+    assert_eq!(vec![1.0], Plane.local_intersect(&r));
+}
+
+#[test]
+fn a_ray_intersecting_a_plane_from_below() {
+    let r = Ray::new(Point::new(0.0, -1.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+
+    assert_eq!(vec![1.0], Plane.local_intersect(&r));
+}
+
+#[test]
+fn intersect_sets_the_object_on_the_intersection() {
+    let p = Object::new(Plane);
+    let r = Ray::new(Point::new(0.0, 1.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+    let xs = p.intersect(&r);
+
+    assert_eq!(1, xs.len());
+    assert_eq!(1.0, xs[0].t);
+    assert!(std::ptr::eq(&p, xs[0].object));
+}
+
+#[test]
+fn a_transformed_plane_acts_as_a_wall() {
+    let mut p = Object::new(Plane);
+    p.set_transform(translation(0.0, 0.0, 5.0) * rotation_x(-PI / 2.0));
+    let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    let xs = p.intersect(&r);
+
+    assert_eq!(1, xs.len());
+    assert_eq!(5.0, xs[0].t);
+    assert_eq!(Vector::new(0.0, 0.0, -1.0), p.normal_at(&r.position(5.0)));
+}
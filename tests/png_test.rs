@@ -0,0 +1,280 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::deflate::zlib_compress;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::png::crc32;
+use ray_tracer_challenge::tone_mapping::ExportOptions;
+use ray_tracer_challenge::tuples::Color;
+
+const SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+fn chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(data);
+    chunk.extend_from_slice(&crc32(&chunk[4..]).to_be_bytes());
+    chunk
+}
+
+fn png(
+    width: u32,
+    height: u32,
+    depth: u8,
+    color_type: u8,
+    extra: &[Vec<u8>],
+    raw: &[u8],
+) -> Vec<u8> {
+    png_with_interlace(width, height, depth, color_type, 0, extra, raw)
+}
+
+fn png_with_interlace(
+    width: u32,
+    height: u32,
+    depth: u8,
+    color_type: u8,
+    interlace: u8,
+    extra: &[Vec<u8>],
+    raw: &[u8],
+) -> Vec<u8> {
+    let mut header = width.to_be_bytes().to_vec();
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[depth, color_type, 0, 0, interlace]);
+
+    let mut png = SIGNATURE.to_vec();
+    png.extend(chunk(b"IHDR", &header));
+    for chunk in extra {
+        png.extend_from_slice(chunk);
+    }
+    png.extend(chunk(b"IDAT", &zlib_compress(raw)));
+    png.extend(chunk(b"IEND", &[]));
+    png
+}
+
+#[test]
+fn computing_a_crc32_checksum() {
+    assert_eq!(0, crc32(b""));
+    assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+    assert_eq!(0xae42_6082, crc32(b"IEND"));
+}
+
+#[test]
+fn writing_a_png_file_starts_with_the_signature_and_header() {
+    let c: Canvas = Canvas::new(5, 3);
+    let png = c.to_png().unwrap();
+
+    assert_eq!(SIGNATURE, png[..8]);
+    assert_eq!(
+        chunk(b"IHDR", &[0, 0, 0, 5, 0, 0, 0, 3, 8, 2, 0, 0, 0]),
+        png[8..33].to_vec()
+    );
+    assert_eq!(chunk(b"IEND", &[]), png[png.len() - 12..].to_vec());
+}
+
+#[test]
+fn a_canvas_survives_a_png_round_trip() {
+    let mut c: Canvas = Canvas::new(17, 9);
+    for y in 0..9 {
+        for x in 0..17 {
+            // multiples of 1/255 survive the 8-bit quantization exactly
+            let color = Color::new(
+                (x * 15) as f64 / 255.0,
+                (y * 31) as f64 / 255.0,
+                ((x * y) % 5 * 51) as f64 / 255.0,
+            );
+            c.write_pixel(x, y, color);
+        }
+    }
+
+    assert_eq!(c, Canvas::from_png(&c.to_png().unwrap()).unwrap());
+}
+
+#[test]
+fn writing_a_png_file_clamps_colors() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(1.5, 0.0, 0.0));
+    c.write_pixel(1, 0, Color::new(0.0, 0.5, -0.5));
+    let read: Canvas = Canvas::from_png(&c.to_png().unwrap()).unwrap();
+
+    assert_eq!(Color::new(1.0, 0.0, 0.0), read.pixel_at(0, 0));
+    assert_eq!(Color::new(0.0, 128.0 / 255.0, 0.0), read.pixel_at(1, 0));
+}
+
+#[test]
+fn empty_canvases_cannot_be_written_as_png() {
+    for (width, height) in [(0, 0), (0, 3), (3, 0)] {
+        let c: Canvas = Canvas::new(width, height);
+        let error = Err(Error::UnsupportedSize {
+            format: "PNG",
+            width,
+            height,
+        });
+
+        assert_eq!(error, c.to_png());
+        assert_eq!(error, c.to_png_with(&ExportOptions::default()));
+    }
+}
+
+#[test]
+fn reading_a_png_file_with_every_filter_type() {
+    // two RGB rows per filter: the second row is filtered against the first
+    let first = [10u8, 20, 30, 40, 50, 60];
+    let second = [15u8, 25, 35, 200, 100, 0];
+    let mut raw = Vec::new();
+    let mut expected = Vec::new();
+
+    for kind in 0..5u8 {
+        raw.push(0);
+        raw.extend_from_slice(&first);
+        raw.push(kind);
+        for i in 0..6 {
+            let a = if i >= 3 { second[i - 3] } else { 0 };
+            let b = first[i];
+            let c = if i >= 3 { first[i - 3] } else { 0 };
+            let prediction = match kind {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                _ => {
+                    let p = a as i16 + b as i16 - c as i16;
+                    let (pa, pb, pc) = (
+                        (p - a as i16).abs(),
+                        (p - b as i16).abs(),
+                        (p - c as i16).abs(),
+                    );
+                    if pa <= pb && pa <= pc {
+                        a
+                    } else if pb <= pc {
+                        b
+                    } else {
+                        c
+                    }
+                }
+            };
+            raw.push(second[i].wrapping_sub(prediction));
+        }
+        expected.push(first);
+        expected.push(second);
+    }
+
+    let c: Canvas = Canvas::from_png(&png(2, 10, 8, 2, &[], &raw)).unwrap();
+
+    for (y, row) in expected.iter().enumerate() {
+        for x in 0..2 {
+            let rgb = &row[x * 3..x * 3 + 3];
+            assert_eq!(
+                Color::new(
+                    rgb[0] as f64 / 255.0,
+                    rgb[1] as f64 / 255.0,
+                    rgb[2] as f64 / 255.0
+                ),
+                c.pixel_at(x, y)
+            );
+        }
+    }
+}
+
+#[test]
+fn reading_a_grayscale_png_file_with_packed_samples() {
+    // 1-bit samples, MSB first: 1 0 1 1 0 in the first row
+    let raw = [0, 0b1011_0000, 0, 0b0100_1000];
+    let c: Canvas = Canvas::from_png(&png(5, 2, 1, 0, &[], &raw)).unwrap();
+
+    let white = Color::white();
+    let black = Color::black();
+    assert_eq!(white, c.pixel_at(0, 0));
+    assert_eq!(black, c.pixel_at(1, 0));
+    assert_eq!(white, c.pixel_at(3, 0));
+    assert_eq!(black, c.pixel_at(4, 0));
+    assert_eq!(white, c.pixel_at(1, 1));
+    assert_eq!(white, c.pixel_at(4, 1));
+}
+
+#[test]
+fn reading_a_16_bit_rgba_png_file_ignores_alpha() {
+    let raw = [0, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x12, 0x34];
+    let c: Canvas = Canvas::from_png(&png(1, 1, 16, 6, &[], &raw)).unwrap();
+
+    assert_eq!(Color::new(1.0, 32768.0 / 65535.0, 0.0), c.pixel_at(0, 0));
+}
+
+#[test]
+fn reading_a_palette_png_file() {
+    let palette = chunk(b"PLTE", &[255, 0, 0, 0, 0, 255, 0, 51, 0]);
+    // 2-bit indices 2, 1, 0
+    let raw = [0, 0b1001_0000];
+    let c: Canvas = Canvas::from_png(&png(3, 1, 2, 3, &[palette], &raw)).unwrap();
+
+    assert_eq!(Color::new(0.0, 0.2, 0.0), c.pixel_at(0, 0));
+    assert_eq!(Color::new(0.0, 0.0, 1.0), c.pixel_at(1, 0));
+    assert_eq!(Color::new(1.0, 0.0, 0.0), c.pixel_at(2, 0));
+}
+
+#[test]
+fn reading_an_interlaced_png_file() {
+    // 3x3 8-bit grayscale, where each pixel's value is its index; the passes
+    // that are non-empty are 1 (0,0), 4 (2,0), 5 (0,2) (2,2), 6 (1,0) (1,2)
+    // and 7 (0,1) (1,1) (2,1)
+    let raw = [0, 0, 0, 2, 0, 6, 8, 0, 1, 0, 7, 0, 3, 4, 5];
+    let c: Canvas = Canvas::from_png(&png_with_interlace(3, 3, 8, 0, 1, &[], &raw)).unwrap();
+
+    for y in 0..3 {
+        for x in 0..3 {
+            let value = (y * 3 + x) as f64 / 255.0;
+            assert_eq!(Color::new(value, value, value), c.pixel_at(x, y));
+        }
+    }
+}
+
+#[test]
+fn ancillary_chunks_are_skipped() {
+    let text = chunk(b"tEXt", b"Comment\0hello");
+    let c: Canvas = Canvas::from_png(&png(1, 1, 8, 0, &[text], &[0, 255])).unwrap();
+
+    assert_eq!(Color::white(), c.pixel_at(0, 0));
+}
+
+#[test]
+fn reading_invalid_png_files_fails() {
+    let malformed = |reason: &str| Error::MalformedImage {
+        format: "PNG",
+        reason: reason.to_string(),
+    };
+    let valid = png(1, 1, 8, 0, &[], &[0, 255]);
+
+    let mut corrupt = valid.clone();
+    corrupt[29] ^= 1;
+    let unknown = chunk(b"ABCD", &[]);
+
+    assert_eq!(
+        Err(malformed("invalid signature")),
+        Canvas::<f64>::from_png(b"P3\n1 1\n255\n0 0 0\n")
+    );
+    assert_eq!(
+        Err(malformed("checksum mismatch in \"IHDR\" chunk")),
+        Canvas::<f64>::from_png(&corrupt)
+    );
+    assert_eq!(
+        Err(Error::TruncatedImage { format: "PNG" }),
+        Canvas::<f64>::from_png(&valid[..valid.len() - 12])
+    );
+    assert_eq!(
+        Err(malformed("bit depth 4 is invalid for color type 2")),
+        Canvas::<f64>::from_png(&png(1, 1, 4, 2, &[], &[0, 0]))
+    );
+    assert_eq!(
+        Err(malformed("unknown critical chunk \"ABCD\"")),
+        Canvas::<f64>::from_png(&png(1, 1, 8, 0, &[unknown], &[0, 0]))
+    );
+    assert_eq!(
+        Err(malformed("unknown filter type 5")),
+        Canvas::<f64>::from_png(&png(1, 1, 8, 0, &[], &[5, 0]))
+    );
+    assert_eq!(
+        Err(malformed("missing palette")),
+        Canvas::<f64>::from_png(&png(1, 1, 8, 3, &[], &[0, 0]))
+    );
+    assert_eq!(
+        Err(Error::TruncatedImage { format: "PNG" }),
+        Canvas::<f64>::from_png(&png(2, 2, 8, 0, &[], &[0, 0, 0]))
+    );
+}
@@ -0,0 +1,140 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::tuples::Color;
+
+#[test]
+fn reading_a_plain_ppm_file() {
+    let ppm = b"P3\n2 2\n255\n255 0 0  0 255 0\n0 0 255 255 255 255\n";
+    let c: Canvas = Canvas::from_ppm(ppm).unwrap();
+
+    assert_eq!(2, c.width());
+    assert_eq!(2, c.height());
+    assert_eq!(Color::new(1.0, 0.0, 0.0), c.pixel_at(0, 0));
+    assert_eq!(Color::new(0.0, 1.0, 0.0), c.pixel_at(1, 0));
+    assert_eq!(Color::new(0.0, 0.0, 1.0), c.pixel_at(0, 1));
+    assert_eq!(Color::new(1.0, 1.0, 1.0), c.pixel_at(1, 1));
+}
+
+#[test]
+fn reading_a_ppm_file_with_comments_and_arbitrary_whitespace() {
+    let ppm = b"# leading comment\nP3 # magic\n1\t# width\n 1\r\n# maxval follows\n255\n10\n\n20 # green\n30";
+    let c: Canvas = Canvas::from_ppm(ppm).unwrap();
+
+    assert_eq!(
+        Color::new(10.0 / 255.0, 20.0 / 255.0, 30.0 / 255.0),
+        c.pixel_at(0, 0)
+    );
+}
+
+#[test]
+fn reading_a_ppm_file_with_a_different_maxval() {
+    let ppm = b"P3\n2 1\n100\n100 50 0 25 75 100\n";
+    let c: Canvas = Canvas::from_ppm(ppm).unwrap();
+
+    assert_eq!(Color::new(1.0, 0.5, 0.0), c.pixel_at(0, 0));
+    assert_eq!(Color::new(0.25, 0.75, 1.0), c.pixel_at(1, 0));
+}
+
+#[test]
+fn reading_a_raw_ppm_file() {
+    let mut ppm = b"P6\n# binary\n2 1\n255\n".to_vec();
+    ppm.extend_from_slice(&[255, 0, 51, 0, 102, 255]);
+    let c: Canvas = Canvas::from_ppm(&ppm).unwrap();
+
+    assert_eq!(Color::new(1.0, 0.0, 0.2), c.pixel_at(0, 0));
+    assert_eq!(Color::new(0.0, 0.4, 1.0), c.pixel_at(1, 0));
+}
+
+#[test]
+fn reading_a_raw_ppm_file_with_16_bit_samples() {
+    let mut ppm = b"P6 1 1 65535\n".to_vec();
+    ppm.extend_from_slice(&[0xff, 0xff, 0x80, 0x00, 0x00, 0x00]);
+    let c: Canvas = Canvas::from_ppm(&ppm).unwrap();
+
+    assert_eq!(Color::new(1.0, 32768.0 / 65535.0, 0.0), c.pixel_at(0, 0));
+}
+
+#[test]
+fn raw_ppm_raster_bytes_may_look_like_whitespace_or_comments() {
+    let mut ppm = b"P6\n1 1\n255\n".to_vec();
+    ppm.extend_from_slice(b"# \n");
+    let c: Canvas = Canvas::from_ppm(&ppm).unwrap();
+
+    assert_eq!(
+        Color::new(35.0 / 255.0, 32.0 / 255.0, 10.0 / 255.0),
+        c.pixel_at(0, 0)
+    );
+}
+
+#[test]
+fn round_tripping_a_canvas_through_ppm() {
+    let mut c = Canvas::new(30, 3);
+    c.fill(Color::new(0.2, 0.4, 0.6));
+    c.write_pixel(29, 2, Color::new(1.0, 0.0, 1.0));
+
+    let read: Canvas = Canvas::from_ppm(c.to_ppm().as_bytes()).unwrap();
+
+    assert_eq!(c, read);
+}
+
+#[test]
+fn rejecting_an_unknown_magic_number() {
+    let result = Canvas::<f64>::from_ppm(b"P5\n1 1\n255\n\0");
+
+    assert_eq!(
+        Err(Error::MalformedImage {
+            format: "PPM",
+            reason: "unknown magic number \"P5\"".to_string()
+        }),
+        result
+    );
+}
+
+#[test]
+fn rejecting_truncated_ppm_files() {
+    let truncated = Error::TruncatedImage { format: "PPM" };
+
+    assert_eq!(Err(truncated.clone()), Canvas::<f64>::from_ppm(b""));
+    assert_eq!(Err(truncated.clone()), Canvas::<f64>::from_ppm(b"P3\n2 2"));
+    assert_eq!(
+        Err(truncated.clone()),
+        Canvas::<f64>::from_ppm(b"P3\n1 2\n255\n0 0 0 255 255")
+    );
+    assert_eq!(
+        Err(truncated.clone()),
+        Canvas::<f64>::from_ppm(b"P6\n1 2\n255\n\0\0\0\0\0")
+    );
+    assert_eq!(Err(truncated), Canvas::<f64>::from_ppm(b"P6\n1 1\n255"));
+}
+
+#[test]
+fn rejecting_malformed_ppm_files() {
+    assert_eq!(
+        Err(Error::MalformedImage {
+            format: "PPM",
+            reason: "expected width, found \"-2\"".to_string()
+        }),
+        Canvas::<f64>::from_ppm(b"P3\n-2 2\n255\n")
+    );
+    assert_eq!(
+        Err(Error::MalformedImage {
+            format: "PPM",
+            reason: "maxval 0 is out of range".to_string()
+        }),
+        Canvas::<f64>::from_ppm(b"P3\n1 1\n0\n0 0 0")
+    );
+    assert_eq!(
+        Err(Error::MalformedImage {
+            format: "PPM",
+            reason: "sample 256 exceeds maxval 255".to_string()
+        }),
+        Canvas::<f64>::from_ppm(b"P3\n1 1\n255\n0 256 0")
+    );
+    assert_eq!(
+        Err(Error::MalformedImage {
+            format: "PPM",
+            reason: "expected sample, found \"1.5\"".to_string()
+        }),
+        Canvas::<f64>::from_ppm(b"P3\n1 1\n255\n0 1.5 0")
+    );
+}
@@ -0,0 +1,89 @@
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::projectile::{plot_trajectory, Environment, Projectile};
+use ray_tracer_challenge::tuples::{Color, Point, Vector};
+
+#[test]
+fn a_projectile_moves_by_its_velocity_and_is_slowed_by_the_environment() {
+    let mut p = Projectile::new(Point::new(0.0, 1.0, 0.0), Vector::new(1.0, 1.0, 0.0));
+    let e = Environment::new(Vector::new(0.0, -0.1, 0.0), Vector::new(-0.01, 0.0, 0.0));
+
+    p.tick(&e);
+
+    assert_eq!(Point::new(1.0, 2.0, 0.0), p.position);
+    assert_eq!(Vector::new(0.99, 0.9, 0.0), p.velocity);
+}
+
+#[test]
+fn a_trajectory_ends_when_the_projectile_hits_the_ground() {
+    let p = Projectile::new(Point::new(0.0, 1.0, 0.0), Vector::new(1.0, 1.0, 0.0));
+    let e = Environment::new(Vector::new(0.0, -0.5, 0.0), Vector::new(0.0, 0.0, 0.0));
+
+    let trajectory: Vec<Point> = p.trajectory(&e).collect();
+
+    assert_eq!(
+        vec![
+            Point::new(1.0, 2.0, 0.0),
+            Point::new(2.0, 2.5, 0.0),
+            Point::new(3.0, 2.5, 0.0),
+            Point::new(4.0, 2.0, 0.0),
+            Point::new(5.0, 1.0, 0.0),
+            Point::new(6.0, -0.5, 0.0),
+        ],
+        trajectory
+    );
+}
+
+#[test]
+fn a_trajectory_ends_when_the_position_is_no_longer_finite() {
+    let e = Environment::new(Vector::new(0.0, -0.1, 0.0), Vector::new(0.0, 0.0, 0.0));
+
+    for velocity in [f64::NAN, f64::INFINITY, f64::MAX] {
+        let p = Projectile::new(
+            Point::new(0.0, 1.0, 0.0),
+            Vector::new(1.0, 1.0, 0.0) * velocity,
+        );
+        let trajectory: Vec<Point> = p.trajectory(&e).collect();
+
+        assert!(trajectory.len() <= 2, "{} ticks", trajectory.len());
+        assert!(trajectory
+            .iter()
+            .all(|p| p.x().is_finite() && p.y().is_finite()));
+    }
+}
+
+#[test]
+fn plotting_a_trajectory_flips_the_y_axis() {
+    let mut c = Canvas::new(5, 4);
+    let red = Color::new(1.0, 0.0, 0.0);
+
+    plot_trajectory(
+        &mut c,
+        vec![Point::new(0.0, 0.0, 0.0), Point::new(2.2, 2.6, 0.0)],
+        red,
+    );
+
+    assert_eq!(red, c.pixel_at(0, 3));
+    assert_eq!(red, c.pixel_at(2, 0));
+    assert_eq!(2, c.pixels().iter().filter(|&&p| p == red).count());
+}
+
+#[test]
+fn plotting_a_trajectory_skips_points_outside_of_the_canvas() {
+    let mut c = Canvas::new(5, 4);
+    let red = Color::new(1.0, 0.0, 0.0);
+
+    plot_trajectory(
+        &mut c,
+        vec![
+            Point::new(-1.0, 1.0, 0.0),
+            Point::new(5.0, 1.0, 0.0),
+            Point::new(1.0, 4.0, 0.0),
+            Point::new(1.0, -1.0, 0.0),
+            Point::new(4.0, 3.0, 0.0),
+        ],
+        red,
+    );
+
+    assert_eq!(red, c.pixel_at(4, 0));
+    assert_eq!(1, c.pixels().iter().filter(|&&p| p == red).count());
+}
@@ -0,0 +1,138 @@
+// the reference variants of the operators are exercised on purpose
+#![allow(clippy::op_ref)]
+
+use ray_tracer_challenge::assert_approx_eq;
+use ray_tracer_challenge::matrices::Matrix;
+use ray_tracer_challenge::quaternions::Quaternion;
+use ray_tracer_challenge::transformations::{rotation_x, rotation_y, rotation_z, Transform};
+use ray_tracer_challenge::tuples::{Point, Vector};
+use std::f64::consts::PI;
+
+#[test]
+fn creating_a_quaternion() {
+    let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(1.0, q.w());
+    assert_eq!(2.0, q.x());
+    assert_eq!(3.0, q.y());
+    assert_eq!(4.0, q.z());
+}
+
+#[test]
+fn creating_a_quaternion_from_an_axis_and_an_angle() {
+    let q = Quaternion::from_axis_angle(&Vector::new(0.0, 0.0, 2.0), PI / 2.0);
+
+    let s = 2.0_f64.sqrt() / 2.0;
+
+    assert_eq!(Quaternion::new(s, 0.0, 0.0, s), q);
+    assert_approx_eq!(1.0, q.magnitude());
+}
+
+#[test]
+fn the_identity_quaternion_is_the_identity_transform() {
+    let q: Quaternion = Quaternion::identity();
+
+    assert_eq!(Matrix::identity(), q.to_matrix());
+}
+
+#[test]
+fn axis_angle_quaternions_match_the_rotation_matrices() {
+    let x = Quaternion::from_axis_angle(&Vector::new(1.0, 0.0, 0.0), PI / 3.0);
+    let y = Quaternion::from_axis_angle(&Vector::new(0.0, 1.0, 0.0), PI / 4.0);
+    let z = Quaternion::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), -PI / 6.0);
+
+    assert_eq!(rotation_x(PI / 3.0), x.to_matrix());
+    assert_eq!(rotation_y(PI / 4.0), Matrix::from(y));
+    assert_eq!(rotation_z(-PI / 6.0), z.to_matrix());
+}
+
+#[test]
+fn multiplying_quaternions_composes_rotations() {
+    let x = Quaternion::from_axis_angle(&Vector::new(1.0, 0.0, 0.0), PI / 2.0);
+    let y = Quaternion::from_axis_angle(&Vector::new(0.0, 1.0, 0.0), PI / 2.0);
+
+    let t = Transform::identity().rotate_x(PI / 2.0).rotate_y(PI / 2.0);
+
+    assert_eq!(t, (y * x).to_matrix());
+    assert_eq!(t, (&y * &x).to_matrix());
+}
+
+#[test]
+fn rotating_a_vector_with_a_quaternion() {
+    let q = Quaternion::from_axis_angle(&Vector::new(0.0, 0.0, 1.0), PI / 2.0);
+    let v = Vector::new(1.0, 0.0, 0.0);
+
+    assert_eq!(Vector::new(0.0, 1.0, 0.0), q.rotate(&v));
+    assert_eq!(q.to_matrix() * v, q.rotate(&v));
+}
+
+#[test]
+fn normalizing_a_quaternion() {
+    let q = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let m = 30.0_f64.sqrt();
+
+    assert_eq!(
+        Quaternion::new(1.0 / m, 2.0 / m, 3.0 / m, 4.0 / m),
+        q.normalize()
+    );
+    assert_approx_eq!(1.0, q.normalize().magnitude());
+}
+
+#[test]
+fn the_conjugate_inverts_a_unit_quaternion() {
+    let q = Quaternion::from_axis_angle(&Vector::new(1.0, 2.0, 3.0), 0.7);
+
+    assert_eq!(Quaternion::identity(), q * q.conjugate());
+}
+
+#[test]
+fn slerp_returns_the_endpoints() {
+    let a = Quaternion::from_axis_angle(&Vector::new(0.0, 1.0, 0.0), 0.3);
+    let b = Quaternion::from_axis_angle(&Vector::new(1.0, 1.0, 0.0), 1.2);
+
+    assert_eq!(a, a.slerp(&b, 0.0));
+    assert_eq!(b, a.slerp(&b, 1.0));
+}
+
+#[test]
+fn slerp_interpolates_the_rotation_angle() {
+    let axis = Vector::new(0.0, 0.0, 1.0);
+    let a = Quaternion::identity();
+    let b = Quaternion::from_axis_angle(&axis, PI / 2.0);
+
+    let halfway = a.slerp(&b, 0.5);
+    let quarter = a.slerp(&b, 0.25);
+
+    assert_eq!(Quaternion::from_axis_angle(&axis, PI / 4.0), halfway);
+    assert_eq!(Quaternion::from_axis_angle(&axis, PI / 8.0), quarter);
+    assert_eq!(
+        Point::new(2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0, 0.0),
+        halfway.to_matrix() * Point::new(1.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn slerp_takes_the_shortest_path() {
+    let axis = Vector::new(0.0, 1.0, 0.0);
+    let a = Quaternion::from_axis_angle(&axis, 0.2);
+    let b = Quaternion::from_axis_angle(&axis, 0.6);
+    // the same orientation as b on the other hemisphere
+    let negated_b = Quaternion::new(-b.w(), -b.x(), -b.y(), -b.z());
+
+    assert_eq!(
+        Quaternion::from_axis_angle(&axis, 0.4),
+        a.slerp(&negated_b, 0.5)
+    );
+}
+
+#[test]
+fn slerp_between_nearly_identical_rotations() {
+    let axis = Vector::new(1.0, 0.0, 0.0);
+    let a = Quaternion::from_axis_angle(&axis, 0.1);
+    let b = Quaternion::from_axis_angle(&axis, 0.1001);
+
+    assert_eq!(
+        Quaternion::from_axis_angle(&axis, 0.10005),
+        a.slerp(&b, 0.5)
+    );
+}
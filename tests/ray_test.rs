@@ -0,0 +1,54 @@
+use ray_tracer_challenge::rays::Ray;
+use ray_tracer_challenge::transformations::{scaling, translation};
+use ray_tracer_challenge::tuples::{Point, Vector};
+
+#[test]
+fn creating_and_querying_a_ray() {
+    let origin = Point::new(1.0, 2.0, 3.0);
+    let direction = Vector::new(4.0, 5.0, 6.0);
+    let r = Ray::new(origin, direction);
+
+    assert_eq!(origin, r.origin);
+    assert_eq!(direction, r.direction);
+}
+
+#[test]
+fn computing_a_point_from_a_distance() {
+    let r = Ray::new(Point::new(2.0, 3.0, 4.0), Vector::new(1.0, 0.0, 0.0));
+
+    assert_eq!(Point::new(2.0, 3.0, 4.0), r.position(0.0));
+    assert_eq!(Point::new(3.0, 3.0, 4.0), r.position(1.0));
+    assert_eq!(Point::new(1.0, 3.0, 4.0), r.position(-1.0));
+    assert_eq!(Point::new(4.5, 3.0, 4.0), r.position(2.5));
+}
+
+#[test]
+fn translating_a_ray() {
+    let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+    let m = translation(3.0, 4.0, 5.0);
+    let r2 = r.transform(&m);
+
+    assert_eq!(Point::new(4.0, 6.0, 8.0), r2.origin);
+    assert_eq!(Vector::new(0.0, 1.0, 0.0), r2.direction);
+}
+
+#[test]
+fn scaling_a_ray() {
+    let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+    let m = scaling(2.0, 3.0, 4.0);
+    let r2 = r.transform(&m);
+
+    assert_eq!(Point::new(2.0, 6.0, 12.0), r2.origin);
+    assert_eq!(Vector::new(0.0, 3.0, 0.0), r2.direction);
+}
+
+#[test]
+fn transforming_a_ray_leaves_the_original_unchanged() {
+    let r = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+    let _ = r.transform(&translation(3.0, 4.0, 5.0));
+
+    assert_eq!(
+        Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0)),
+        r
+    );
+}
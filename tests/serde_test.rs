@@ -0,0 +1,116 @@
+#![cfg(feature = "serde")]
+
+use ray_tracer_challenge::matrices::Matrix;
+use ray_tracer_challenge::quaternions::Quaternion;
+use ray_tracer_challenge::transformations::Transform;
+use ray_tracer_challenge::tuples::{Color, Point, Tuple, Vector};
+use std::f64::consts::PI;
+
+#[test]
+fn points_serialize_without_their_w_component() {
+    let p = Point::new(1.0, -2.5, 3.0);
+
+    assert_eq!("[1.0,-2.5,3.0]", serde_json::to_string(&p).unwrap());
+}
+
+#[test]
+fn vectors_serialize_without_their_w_component() {
+    let v = Vector::new(0.5, 0.0, -1.0);
+
+    assert_eq!("[0.5,0.0,-1.0]", serde_json::to_string(&v).unwrap());
+}
+
+#[test]
+fn tuples_serialize_all_four_components() {
+    let t = Tuple::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!("[1.0,2.0,3.0,4.0]", serde_json::to_string(&t).unwrap());
+}
+
+#[test]
+fn colors_serialize_as_rgb_triples() {
+    let c = Color::new(0.25, 0.5, 1.0);
+
+    assert_eq!("[0.25,0.5,1.0]", serde_json::to_string(&c).unwrap());
+}
+
+#[test]
+fn round_tripping_tuples_points_vectors_and_colors() {
+    let t = Tuple::new(1.5, 2.0, -3.0, 0.5);
+    let p = Point::new(1.5, 2.0, -3.0);
+    let v = Vector::new(1.5, 2.0, -3.0);
+    let c = Color::new(0.1, 0.2, 0.3);
+
+    let t2: Tuple = serde_json::from_str(&serde_json::to_string(&t).unwrap()).unwrap();
+    let p2: Point = serde_json::from_str(&serde_json::to_string(&p).unwrap()).unwrap();
+    let v2: Vector = serde_json::from_str(&serde_json::to_string(&v).unwrap()).unwrap();
+    let c2: Color = serde_json::from_str(&serde_json::to_string(&c).unwrap()).unwrap();
+
+    assert_eq!(t, t2);
+    assert_eq!(p, p2);
+    assert_eq!(v, v2);
+    assert_eq!(c, c2);
+}
+
+#[test]
+fn deserialized_points_and_vectors_get_their_w_component() {
+    let p: Point = serde_json::from_str("[1, 2, 3]").unwrap();
+    let v: Vector = serde_json::from_str("[1, 2, 3]").unwrap();
+
+    assert_eq!(Tuple::new(1.0, 2.0, 3.0, 1.0), Tuple::from(p));
+    assert_eq!(Tuple::new(1.0, 2.0, 3.0, 0.0), Tuple::from(v));
+}
+
+#[test]
+fn deserializing_rejects_the_wrong_number_of_components() {
+    assert!(serde_json::from_str::<Point>("[1, 2, 3, 1]").is_err());
+    assert!(serde_json::from_str::<Color>("[1, 2]").is_err());
+    assert!(serde_json::from_str::<Tuple>("[1, 2, 3]").is_err());
+}
+
+#[test]
+fn round_tripping_single_precision_values() {
+    let p: Point<f32> = Point::new(1.5, 2.0, -3.0);
+    let json = serde_json::to_string(&p).unwrap();
+
+    assert_eq!("[1.5,2.0,-3.0]", json);
+    assert_eq!(p, serde_json::from_str::<Point<f32>>(&json).unwrap());
+}
+
+#[test]
+fn matrices_serialize_as_rows() {
+    let m = Matrix::new([[1.0, 2.0], [3.0, 4.0]]);
+
+    assert_eq!("[[1.0,2.0],[3.0,4.0]]", serde_json::to_string(&m).unwrap());
+}
+
+#[test]
+fn round_tripping_a_transform() {
+    let t = Transform::identity()
+        .rotate_x(PI / 3.0)
+        .scale(2.0, 1.0, 0.5)
+        .translate(1.0, -2.0, 3.0);
+
+    let json = serde_json::to_string(&t).unwrap();
+
+    assert_eq!(t, serde_json::from_str::<Transform>(&json).unwrap());
+}
+
+#[test]
+fn deserializing_rejects_matrices_of_the_wrong_size() {
+    assert!(serde_json::from_str::<Matrix<2>>("[[1, 2], [3, 4], [5, 6]]").is_err());
+    assert!(serde_json::from_str::<Matrix<2>>("[[1, 2], [3]]").is_err());
+    assert!(serde_json::from_str::<Matrix<2>>("[[1, 2]]").is_err());
+}
+
+#[test]
+fn round_tripping_a_quaternion() {
+    let q = Quaternion::from_axis_angle(&Vector::new(1.0, 1.0, 0.0), 0.5);
+    let json = serde_json::to_string(&q).unwrap();
+
+    assert_eq!(q, serde_json::from_str::<Quaternion>(&json).unwrap());
+    assert_eq!(
+        "[1.0,0.0,0.0,0.0]",
+        serde_json::to_string(&Quaternion::<f64>::identity()).unwrap()
+    );
+}
@@ -0,0 +1,130 @@
+use ray_tracer_challenge::intersections::Normal;
+use ray_tracer_challenge::materials::Material;
+use ray_tracer_challenge::matrices::Matrix;
+use ray_tracer_challenge::rays::Ray;
+use ray_tracer_challenge::shapes::{Object, Shape};
+use ray_tracer_challenge::spheres::Sphere;
+use ray_tracer_challenge::transformations::{rotation_z, scaling, translation};
+use ray_tracer_challenge::tuples::{Color, Point, Vector};
+use std::cell::RefCell;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use std::rc::Rc;
+
+// records the object space ray it is intersected with, and uses the object
+// space point as its normal
+#[derive(Debug, Default)]
+struct TestShape {
+    saved_ray: Rc<RefCell<Option<Ray>>>,
+}
+
+impl Shape for TestShape {
+    fn local_intersect(&self, ray: &Ray) -> Vec<f64> {
+        *self.saved_ray.borrow_mut() = Some(*ray);
+        Vec::new()
+    }
+
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        Vector::new(point.x(), point.y(), point.z())
+    }
+}
+
+#[test]
+fn the_default_transformation() {
+    let s = Object::new(TestShape::default());
+
+    assert_eq!(&Matrix::identity(), s.transform());
+}
+
+#[test]
+fn assigning_a_transformation() {
+    let mut s = Object::new(TestShape::default());
+    s.set_transform(translation(2.0, 3.0, 4.0));
+
+    assert_eq!(&translation(2.0, 3.0, 4.0), s.transform());
+}
+
+#[test]
+#[should_panic(expected = "must be invertible")]
+fn a_singular_transformation_is_rejected() {
+    let mut s = Object::new(TestShape::default());
+    s.set_transform(scaling(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn the_default_material() {
+    let s = Object::new(TestShape::default());
+
+    assert_eq!(&Material::new(), s.material());
+    assert_eq!(Color::new(1.0, 1.0, 1.0), s.material().color);
+    assert_eq!(0.1, s.material().ambient);
+    assert_eq!(0.9, s.material().diffuse);
+    assert_eq!(0.9, s.material().specular);
+    assert_eq!(200.0, s.material().shininess);
+}
+
+#[test]
+fn assigning_a_material() {
+    let mut s = Object::new(TestShape::default());
+    let m = Material {
+        ambient: 1.0,
+        ..Material::new()
+    };
+    s.set_material(m);
+
+    assert_eq!(&m, s.material());
+}
+
+#[test]
+fn intersecting_a_scaled_shape_with_a_ray() {
+    let shape = TestShape::default();
+    let saved_ray = Rc::clone(&shape.saved_ray);
+    let mut s = Object::new(shape);
+    s.set_transform(scaling(2.0, 2.0, 2.0));
+
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    assert!(s.intersect(&r).is_empty());
+
+    let local = Ray::new(Point::new(0.0, 0.0, -2.5), Vector::new(0.0, 0.0, 0.5));
+    assert_eq!(Some(local), *saved_ray.borrow());
+}
+
+#[test]
+fn intersecting_a_translated_shape_with_a_ray() {
+    let shape = TestShape::default();
+    let saved_ray = Rc::clone(&shape.saved_ray);
+    let mut s = Object::new(shape);
+    s.set_transform(translation(5.0, 0.0, 0.0));
+
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    s.intersect(&r);
+
+    let local = Ray::new(Point::new(-5.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    assert_eq!(Some(local), *saved_ray.borrow());
+}
+
+#[test]
+fn computing_the_normal_on_a_translated_shape() {
+    let mut s = Object::new(TestShape::default());
+    s.set_transform(translation(0.0, 1.0, 0.0));
+    let n = s.normal_at(&Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+    assert_eq!(Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2), n);
+}
+
+#[test]
+fn computing_the_normal_on_a_transformed_shape() {
+    let mut s = Object::new(TestShape::default());
+    s.set_transform(scaling(1.0, 0.5, 1.0) * rotation_z(PI / 5.0));
+    let n = s.normal_at(&Point::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+    assert_eq!(Vector::new(0.0, 0.97014, -0.24254), n);
+}
+
+#[test]
+fn objects_of_different_shapes_can_share_a_collection() {
+    let objects: Vec<Object> = vec![Object::new(TestShape::default()), Object::new(Sphere)];
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let hits: Vec<usize> = objects.iter().map(|o| o.intersect(&r).len()).collect();
+
+    assert_eq!(vec![0, 2], hits);
+}
@@ -0,0 +1,158 @@
+use ray_tracer_challenge::intersections::{Intersection, Normal};
+use ray_tracer_challenge::rays::Ray;
+use ray_tracer_challenge::shapes::{Object, Shape};
+use ray_tracer_challenge::spheres::Sphere;
+use ray_tracer_challenge::transformations::{rotation_z, scaling, translation};
+use ray_tracer_challenge::tuples::{Point, Vector};
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+#[test]
+fn a_ray_intersects_a_sphere_at_two_points() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let s: Object = Object::new(Sphere);
+    let xs = s.intersect(&r);
+
+    assert_eq!(2, xs.len());
+    assert_eq!(4.0, xs[0].t);
+    assert_eq!(6.0, xs[1].t);
+}
+
+#[test]
+fn a_ray_intersects_a_sphere_at_a_tangent() {
+    let r = Ray::new(Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let s: Object = Object::new(Sphere);
+    let xs = s.intersect(&r);
+
+    assert_eq!(2, xs.len());
+    assert_eq!(5.0, xs[0].t);
+    assert_eq!(5.0, xs[1].t);
+}
+
+#[test]
+fn a_ray_misses_a_sphere() {
+    let r = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let s: Object = Object::new(Sphere);
+
+    assert!(s.intersect(&r).is_empty());
+}
+
+#[test]
+fn a_ray_originates_inside_a_sphere() {
+    let r = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    let s: Object = Object::new(Sphere);
+    let xs = s.intersect(&r);
+
+    assert_eq!(2, xs.len());
+    assert_eq!(-1.0, xs[0].t);
+    assert_eq!(1.0, xs[1].t);
+}
+
+#[test]
+fn a_sphere_is_behind_a_ray() {
+    let r = Ray::new(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+    let s: Object = Object::new(Sphere);
+    let xs = s.intersect(&r);
+
+    assert_eq!(2, xs.len());
+    assert_eq!(-6.0, xs[0].t);
+    assert_eq!(-4.0, xs[1].t);
+    assert_eq!(None, xs.hit());
+}
+
+#[test]
+fn the_local_intersections_of_a_sphere_are_in_order() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+
+    assert_eq!(vec![4.0, 6.0], Sphere.local_intersect(&r));
+}
+
+#[test]
+fn intersect_sets_the_object_on_the_intersection() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let s: Object = Object::new(Sphere);
+    let xs = s.intersect(&r);
+
+    assert!(std::ptr::eq(&s, xs[0].object));
+    assert!(std::ptr::eq(&s, xs[1].object));
+}
+
+#[test]
+fn intersecting_a_scaled_sphere_with_a_ray() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let mut s: Object = Object::new(Sphere);
+    s.set_transform(scaling(2.0, 2.0, 2.0));
+    let xs = s.intersect(&r);
+
+    assert_eq!(2, xs.len());
+    assert_eq!(3.0, xs[0].t);
+    assert_eq!(7.0, xs[1].t);
+}
+
+#[test]
+fn intersecting_a_translated_sphere_with_a_ray() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let mut s: Object = Object::new(Sphere);
+    s.set_transform(translation(5.0, 0.0, 0.0));
+
+    assert!(s.intersect(&r).is_empty());
+}
+
+#[test]
+fn the_normal_on_a_sphere_at_points_on_the_axes() {
+    let s: Object = Object::new(Sphere);
+
+    assert_eq!(
+        Vector::new(1.0, 0.0, 0.0),
+        s.normal_at(&Point::new(1.0, 0.0, 0.0))
+    );
+    assert_eq!(
+        Vector::new(0.0, 1.0, 0.0),
+        s.normal_at(&Point::new(0.0, 1.0, 0.0))
+    );
+    assert_eq!(
+        Vector::new(0.0, 0.0, 1.0),
+        s.normal_at(&Point::new(0.0, 0.0, 1.0))
+    );
+}
+
+#[test]
+fn the_normal_on_a_sphere_at_a_nonaxial_point() {
+    let s: Object = Object::new(Sphere);
+    let a = 3f64.sqrt() / 3.0;
+    let n = s.normal_at(&Point::new(a, a, a));
+
+    assert_eq!(Vector::new(a, a, a), n);
+    assert_eq!(n.normalize(), n);
+}
+
+#[test]
+fn computing_the_normal_on_a_translated_sphere() {
+    let mut s: Object = Object::new(Sphere);
+    s.set_transform(translation(0.0, 1.0, 0.0));
+    let n = s.normal_at(&Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+    assert_eq!(Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2), n);
+}
+
+#[test]
+fn computing_the_normal_on_a_transformed_sphere() {
+    let mut s: Object = Object::new(Sphere);
+    s.set_transform(scaling(1.0, 0.5, 1.0) * rotation_z(PI / 5.0));
+    let n = s.normal_at(&Point::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
+
+    assert_eq!(Vector::new(0.0, 0.97014, -0.24254), n);
+}
+
+#[test]
+fn precomputing_the_hit_on_a_sphere() {
+    let r = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    let mut s: Object = Object::new(Sphere);
+    s.set_transform(translation(0.0, 0.0, 1.0));
+    let xs = s.intersect(&r);
+    let comps = xs.hit().map(|hit| hit.prepare_computations(&r)).unwrap();
+
+    assert_eq!(Intersection::new(5.0, &s), *xs.hit().unwrap());
+    assert_eq!(Point::new(0.0, 0.0, 0.0), comps.point);
+    assert_eq!(Vector::new(0.0, 0.0, -1.0), comps.normalv);
+    assert!(comps.over_point.z() < comps.point.z());
+}
@@ -0,0 +1,111 @@
+use ray_tracer_challenge::assert_approx_eq;
+use ray_tracer_challenge::canvas::Canvas;
+use ray_tracer_challenge::tone_mapping::{ExportOptions, Exposure, ToneMap, DEFAULT_KEY};
+use ray_tracer_challenge::tuples::Color;
+
+#[test]
+fn clamping_leaves_colors_unchanged() {
+    let c = Color::new(1.5, -0.5, 0.25);
+
+    assert_eq!(c, ToneMap::Clamp.apply(c));
+}
+
+#[test]
+fn reinhard_compresses_luminance_and_keeps_hue() {
+    let c = Color::new(1.0, 1.0, 1.0);
+    let mapped = ToneMap::Reinhard.apply(c);
+
+    assert_eq!(Color::new(0.5, 0.5, 0.5), mapped);
+
+    let c = Color::new(4.0, 2.0, 0.0);
+    let mapped = ToneMap::Reinhard.apply(c);
+    let luminance = c.luminance();
+
+    assert_approx_eq!(luminance / (1.0 + luminance), mapped.luminance());
+    assert_approx_eq!(0.5, mapped.green() / mapped.red());
+}
+
+#[test]
+fn extended_reinhard_maps_white_to_one() {
+    let tone_map = ToneMap::ReinhardExtended { white: 4.0 };
+
+    assert_eq!(
+        Color::new(1.0, 1.0, 1.0),
+        tone_map.apply(Color::new(4.0, 4.0, 4.0))
+    );
+    assert_eq!(
+        Color::new(0.53125, 0.53125, 0.53125),
+        tone_map.apply(Color::new(1.0, 1.0, 1.0))
+    );
+    assert_eq!(Color::black(), tone_map.apply(Color::<f64>::black()));
+}
+
+#[test]
+fn the_aces_curve_saturates_at_one() {
+    let mapped = ToneMap::Aces.apply(Color::new(0.0, 0.18, 1000.0));
+
+    assert_approx_eq!(0.0, mapped.red());
+    assert_approx_eq!(0.26690, mapped.green());
+    assert_approx_eq!(1.0, mapped.blue());
+}
+
+#[test]
+fn the_log_average_luminance_is_a_geometric_mean() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(0.1, 0.1, 0.1));
+    c.write_pixel(1, 0, Color::new(10.0, 10.0, 10.0));
+
+    assert_approx_eq!(1.0, c.log_average_luminance(), epsilon = 0.001);
+}
+
+#[test]
+fn exposure_is_given_in_stops() {
+    let mut c: Canvas = Canvas::new(1, 1);
+    c.write_pixel(0, 0, Color::new(0.25, 0.5, 1.0));
+    let options = ExportOptions {
+        exposure: Exposure::Stops(-1.0),
+        ..ExportOptions::default()
+    };
+
+    assert_eq!(
+        Color::new(0.125, 0.25, 0.5),
+        c.tone_mapped(&options).pixel_at(0, 0)
+    );
+}
+
+#[test]
+fn auto_exposure_maps_the_log_average_to_the_key() {
+    let mut c: Canvas = Canvas::new(2, 1);
+    c.write_pixel(0, 0, Color::new(5.0, 5.0, 5.0));
+    c.write_pixel(1, 0, Color::new(20.0, 20.0, 20.0));
+    let options = ExportOptions {
+        exposure: Exposure::Auto { key: DEFAULT_KEY },
+        ..ExportOptions::default()
+    };
+    let mapped = c.tone_mapped(&options);
+
+    assert_approx_eq!(DEFAULT_KEY, mapped.log_average_luminance(), epsilon = 0.001);
+}
+
+#[test]
+fn the_default_options_export_the_same_image() {
+    let mut c: Canvas = Canvas::new(2, 2);
+    c.write_pixel(0, 0, Color::new(1.5, 0.0, 0.5));
+    c.write_pixel(1, 1, Color::new(0.25, -1.0, 0.75));
+    let options = ExportOptions::default();
+
+    assert_eq!(c.to_ppm(), c.to_ppm_with(&options));
+    assert_eq!(c.to_png().unwrap(), c.to_png_with(&options).unwrap());
+}
+
+#[test]
+fn tone_mapping_is_applied_before_quantizing() {
+    let mut c: Canvas = Canvas::new(1, 1);
+    c.write_pixel(0, 0, Color::new(3.0, 1.0, 0.0));
+    let options = ExportOptions {
+        tone_map: ToneMap::Aces,
+        ..ExportOptions::default()
+    };
+
+    assert_eq!("P3\n1 1\n255\n243 205 0\n", c.to_ppm_with(&options));
+}
@@ -0,0 +1,202 @@
+use ray_tracer_challenge::transformations::{
+    rotation_x, rotation_y, rotation_z, scaling, shearing, translation, Transform,
+};
+use ray_tracer_challenge::tuples::{Point, Vector};
+use std::f64::consts::PI;
+
+#[test]
+fn multiplying_by_a_translation_matrix() {
+    let transform = translation(5.0, -3.0, 2.0);
+    let p = Point::new(-3.0, 4.0, 5.0);
+
+    assert_eq!(Point::new(2.0, 1.0, 7.0), transform * p);
+}
+
+#[test]
+fn multiplying_by_the_inverse_of_a_translation_matrix() {
+    let transform = translation(5.0, -3.0, 2.0);
+    let inv = transform.inverse().unwrap();
+    let p = Point::new(-3.0, 4.0, 5.0);
+
+    assert_eq!(Point::new(-8.0, 7.0, 3.0), inv * p);
+}
+
+#[test]
+fn translation_does_not_affect_vectors() {
+    let transform = translation(5.0, -3.0, 2.0);
+    let v = Vector::new(-3.0, 4.0, 5.0);
+
+    assert_eq!(v, transform * v);
+}
+
+#[test]
+fn a_scaling_matrix_applied_to_a_point() {
+    let transform = scaling(2.0, 3.0, 4.0);
+    let p = Point::new(-4.0, 6.0, 8.0);
+
+    assert_eq!(Point::new(-8.0, 18.0, 32.0), transform * p);
+}
+
+#[test]
+fn a_scaling_matrix_applied_to_a_vector() {
+    let transform = scaling(2.0, 3.0, 4.0);
+    let v = Vector::new(-4.0, 6.0, 8.0);
+
+    assert_eq!(Vector::new(-8.0, 18.0, 32.0), transform * v);
+}
+
+#[test]
+fn multiplying_by_the_inverse_of_a_scaling_matrix() {
+    let transform = scaling(2.0, 3.0, 4.0);
+    let inv = transform.inverse().unwrap();
+    let v = Vector::new(-4.0, 6.0, 8.0);
+
+    assert_eq!(Vector::new(-2.0, 2.0, 2.0), inv * v);
+}
+
+#[test]
+fn reflection_is_scaling_by_a_negative_value() {
+    let transform = scaling(-1.0, 1.0, 1.0);
+    let p = Point::new(2.0, 3.0, 4.0);
+
+    assert_eq!(Point::new(-2.0, 3.0, 4.0), transform * p);
+}
+
+#[test]
+fn rotating_a_point_around_the_x_axis() {
+    let p = Point::new(0.0, 1.0, 0.0);
+    let half_quarter = rotation_x(PI / 4.0);
+    let full_quarter = rotation_x(PI / 2.0);
+
+    let s = 2.0_f64.sqrt() / 2.0;
+
+    assert_eq!(Point::new(0.0, s, s), half_quarter * p);
+    assert_eq!(Point::new(0.0, 0.0, 1.0), full_quarter * p);
+}
+
+#[test]
+fn the_inverse_of_an_x_rotation_rotates_in_the_opposite_direction() {
+    let p = Point::new(0.0, 1.0, 0.0);
+    let half_quarter = rotation_x(PI / 4.0);
+    let inv = half_quarter.inverse().unwrap();
+
+    let s = 2.0_f64.sqrt() / 2.0;
+
+    assert_eq!(Point::new(0.0, s, -s), inv * p);
+}
+
+#[test]
+fn rotating_a_point_around_the_y_axis() {
+    let p = Point::new(0.0, 0.0, 1.0);
+    let half_quarter = rotation_y(PI / 4.0);
+    let full_quarter = rotation_y(PI / 2.0);
+
+    let s = 2.0_f64.sqrt() / 2.0;
+
+    assert_eq!(Point::new(s, 0.0, s), half_quarter * p);
+    assert_eq!(Point::new(1.0, 0.0, 0.0), full_quarter * p);
+}
+
+#[test]
+fn rotating_a_point_around_the_z_axis() {
+    let p = Point::new(0.0, 1.0, 0.0);
+    let half_quarter = rotation_z(PI / 4.0);
+    let full_quarter = rotation_z(PI / 2.0);
+
+    let s = 2.0_f64.sqrt() / 2.0;
+
+    assert_eq!(Point::new(-s, s, 0.0), half_quarter * p);
+    assert_eq!(Point::new(-1.0, 0.0, 0.0), full_quarter * p);
+}
+
+#[test]
+fn shearing_moves_each_component_in_proportion_to_the_others() {
+    let p = Point::new(2.0, 3.0, 4.0);
+
+    assert_eq!(
+        Point::new(5.0, 3.0, 4.0),
+        shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0) * p
+    );
+    assert_eq!(
+        Point::new(6.0, 3.0, 4.0),
+        shearing(0.0, 1.0, 0.0, 0.0, 0.0, 0.0) * p
+    );
+    assert_eq!(
+        Point::new(2.0, 5.0, 4.0),
+        shearing(0.0, 0.0, 1.0, 0.0, 0.0, 0.0) * p
+    );
+    assert_eq!(
+        Point::new(2.0, 7.0, 4.0),
+        shearing(0.0, 0.0, 0.0, 1.0, 0.0, 0.0) * p
+    );
+    assert_eq!(
+        Point::new(2.0, 3.0, 6.0),
+        shearing(0.0, 0.0, 0.0, 0.0, 1.0, 0.0) * p
+    );
+    assert_eq!(
+        Point::new(2.0, 3.0, 7.0),
+        shearing(0.0, 0.0, 0.0, 0.0, 0.0, 1.0) * p
+    );
+}
+
+#[test]
+fn individual_transformations_are_applied_in_sequence() {
+    let p = Point::new(1.0, 0.0, 1.0);
+    let a = rotation_x(PI / 2.0);
+    let b = scaling(5.0, 5.0, 5.0);
+    let c = translation(10.0, 5.0, 7.0);
+
+    let p2 = a * p;
+    assert_eq!(Point::new(1.0, -1.0, 0.0), p2);
+
+    let p3 = b * p2;
+    assert_eq!(Point::new(5.0, -5.0, 0.0), p3);
+
+    let p4 = c * p3;
+    assert_eq!(Point::new(15.0, 0.0, 7.0), p4);
+}
+
+#[test]
+fn chained_transformations_must_be_applied_in_reverse_order() {
+    let p = Point::new(1.0, 0.0, 1.0);
+    let a = rotation_x(PI / 2.0);
+    let b = scaling(5.0, 5.0, 5.0);
+    let c = translation(10.0, 5.0, 7.0);
+
+    let t = c * b * a;
+
+    assert_eq!(Point::new(15.0, 0.0, 7.0), t * p);
+}
+
+#[test]
+fn fluent_transformations_are_applied_in_the_order_they_are_chained() {
+    let p = Point::new(1.0, 0.0, 1.0);
+
+    let t = Transform::identity()
+        .rotate_x(PI / 2.0)
+        .scale(5.0, 5.0, 5.0)
+        .translate(10.0, 5.0, 7.0);
+
+    assert_eq!(Point::new(15.0, 0.0, 7.0), t * p);
+}
+
+#[test]
+fn fluent_transformations_match_their_constructors() {
+    let t = Transform::identity()
+        .rotate_y(PI / 3.0)
+        .rotate_z(PI / 6.0)
+        .shear(1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+
+    let result =
+        shearing(1.0, 0.0, 0.0, 0.0, 0.0, 1.0) * rotation_z(PI / 6.0) * rotation_y(PI / 3.0);
+
+    assert_eq!(result, t);
+}
+
+#[test]
+fn fluent_translation_leaves_vectors_unchanged() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    let t = Transform::identity().translate(10.0, 5.0, 7.0);
+
+    assert_eq!(v, t * v);
+}
@@ -1,514 +1,532 @@
-use ray_tracer_challenge::tuples::{cross, dot, Color, Point, Tuple, Value, Vector};
+// the reference variants of the operators are exercised on purpose, and the
+// original assertions compare against literal bools
+#![allow(clippy::op_ref, clippy::bool_assert_comparison)]
+
+use ray_tracer_challenge::assert_approx_eq;
+use ray_tracer_challenge::error::Error;
+use ray_tracer_challenge::scalar::Scalar;
+use ray_tracer_challenge::tuples::{cross, dot, schlick, Color, Point, Tuple, Value, Vector};
+use std::convert::TryFrom;
 
 #[test]
 fn it_creates_a_tuple() {
-    let p = Tuple::new(vec![1.0, 2.0, 3.0, 1.0]);
+    let p = Tuple::new(1.0, 2.0, 3.0, 1.0);
     let val: Vec<f32> = vec![1.0, 2.0, 3.0, 1.0];
     assert_eq!(&val, p.value());
 }
 
 #[test]
 fn it_creates_a_point() {
-    let p = Point::new(vec![1.0, 2.0, 3.0]);
+    let p = Point::new(1.0, 2.0, 3.0);
     let val: Vec<f32> = vec![1.0, 2.0, 3.0, 1.0];
     assert_eq!(&val, p.value());
 }
 
 #[test]
 fn it_creates_a_vector() {
-    let v = Vector::new(vec![1.0, 2.0, 3.0]);
+    let v = Vector::new(1.0, 2.0, 3.0);
     let val: Vec<f32> = vec![1.0, 2.0, 3.0, 0.0];
     assert_eq!(&val, v.value());
 }
 
 #[test]
 fn it_compares_two_tuples_for_equality() {
-    let p1 = Point::new(vec![1.0, 2.0, 3.0]);
-    let p2 = Point::new(vec![1.00001, 2.0, 3.0]);
-    let p3 = Point::new(vec![1.0 - 1.0 * 10.0 * f32::EPSILON, 2.0, 3.0]);
-    let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
-    let v2 = Vector::new(vec![1.0, 2.0, 3.0]);
+    let p1 = Point::new(1.0, 2.0, 3.0);
+    let p2 = Point::new(1.00001, 2.0, 3.0);
+    let p3 = Point::new(1.0 - 0.5 * <f64 as Scalar>::EPSILON, 2.0, 3.0);
+    let v1 = Vector::new(1.0, 2.0, 3.0);
+    let v2 = Vector::new(1.0, 2.0, 3.0);
 
     assert_eq!(false, p1 == p2);
-    assert_eq!(false, p1 == p3);
+    assert_eq!(false, p1 != p3);
     assert_eq!(false, v1 != v2);
 }
 
+#[test]
+fn it_compares_two_f32_tuples_with_the_f32_epsilon() {
+    let p1: Point<f32> = Point::new(1.0, 2.0, 3.0);
+    let p2: Point<f32> = Point::new(1.00001, 2.0, 3.0);
+    let p3: Point<f32> = Point::new(1.001, 2.0, 3.0);
+
+    assert!(p1 == p2);
+    assert!(p1 != p3);
+}
+
 #[test]
 fn it_adds_two_tuples() {
-    let t1 = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let t2 = Tuple::new(vec![-2.0, 3.0, 1.0, 2.0]);
+    let t1 = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let t2 = Tuple::new(-2.0, 3.0, 1.0, 2.0);
     let sum = t1 + t2;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 4.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 4.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_two_tuple_refs() {
-    let t1 = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let t2 = Tuple::new(vec![-2.0, 3.0, 1.0, 2.0]);
+    let t1 = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let t2 = Tuple::new(-2.0, 3.0, 1.0, 2.0);
     let sum = &t1 + &t2;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 4.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 4.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_and_a_tuple_ref() {
-    let t1 = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let t2 = Tuple::new(vec![-2.0, 3.0, 1.0, 2.0]);
+    let t1 = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let t2 = Tuple::new(-2.0, 3.0, 1.0, 2.0);
     let sum = t1 + &t2;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 4.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 4.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_ref_and_a_tuple() {
-    let t1 = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let t2 = Tuple::new(vec![-2.0, 3.0, 1.0, 2.0]);
+    let t1 = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let t2 = Tuple::new(-2.0, 3.0, 1.0, 2.0);
     let sum = &t1 + t2;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 4.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 4.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_and_a_point() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = t + p;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_ref_and_a_point_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &t + &p;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_and_a_point_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = t + &p;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_ref_and_a_point() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &t + p;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_and_a_tuple() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = p + t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_ref_and_a_tuple_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &p + &t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_and_a_tuple_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = p + &t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_ref_and_a_tuple() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &p + t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 3.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 3.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_and_a_vector() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = t + v;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_ref_and_a_vector_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = &t + &v;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_and_a_vector_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = t + &v;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_tuple_ref_and_a_vector() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = &t + v;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_and_a_tuple() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = v + t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_ref_and_a_tuple_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = &v + &t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_and_a_tuple_ref() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = v + &t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_ref_and_a_tuple() {
-    let t = Tuple::new(vec![3.0, -2.0, 5.0, 2.0]);
-    let v = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let t = Tuple::new(3.0, -2.0, 5.0, 2.0);
+    let v = Vector::new(-2.0, 3.0, 1.0);
     let sum = &v + t;
 
-    let result = Tuple::new(vec![1.0, 1.0, 6.0, 2.0]);
+    let result = Tuple::new(1.0, 1.0, 6.0, 2.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_two_vectors() {
-    let v1 = Vector::new(vec![3.0, -2.0, 5.0]);
-    let v2 = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let v1 = Vector::new(3.0, -2.0, 5.0);
+    let v2 = Vector::new(-2.0, 3.0, 1.0);
     let sum = v1 + v2;
 
-    let result = Vector::new(vec![1.0, 1.0, 6.0]);
+    let result = Vector::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_two_vectors_refs() {
-    let v1 = Vector::new(vec![3.0, -2.0, 5.0]);
-    let v2 = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let v1 = Vector::new(3.0, -2.0, 5.0);
+    let v2 = Vector::new(-2.0, 3.0, 1.0);
     let sum = &v1 + &v2;
 
-    let result = Vector::new(vec![1.0, 1.0, 6.0]);
+    let result = Vector::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_and_a_vector_ref() {
-    let v1 = Vector::new(vec![3.0, -2.0, 5.0]);
-    let v2 = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let v1 = Vector::new(3.0, -2.0, 5.0);
+    let v2 = Vector::new(-2.0, 3.0, 1.0);
     let sum = v1 + &v2;
 
-    let result = Vector::new(vec![1.0, 1.0, 6.0]);
+    let result = Vector::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_ref_and_a_vector() {
-    let v1 = Vector::new(vec![3.0, -2.0, 5.0]);
-    let v2 = Vector::new(vec![-2.0, 3.0, 1.0]);
+    let v1 = Vector::new(3.0, -2.0, 5.0);
+    let v2 = Vector::new(-2.0, 3.0, 1.0);
     let sum = &v1 + v2;
 
-    let result = Vector::new(vec![1.0, 1.0, 6.0]);
+    let result = Vector::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_and_a_point() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = v + p;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_ref_and_a_point_ref() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &v + &p;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_and_a_point_ref() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = v + &p;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_vector_ref_and_a_point() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &v + p;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_and_a_vector() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = p + v;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_ref_and_a_vector_ref() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &p + &v;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_and_a_vector_ref() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = p + &v;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn it_adds_a_point_ref_and_a_vector() {
-    let v = Vector::new(vec![3.0, -2.0, 5.0]);
-    let p = Point::new(vec![-2.0, 3.0, 1.0]);
+    let v = Vector::new(3.0, -2.0, 5.0);
+    let p = Point::new(-2.0, 3.0, 1.0);
     let sum = &p + v;
 
-    let result = Point::new(vec![1.0, 1.0, 6.0]);
+    let result = Point::new(1.0, 1.0, 6.0);
 
     assert_eq!(result, sum);
 }
 
 #[test]
 fn subtracting_two_points() {
-    let p1 = Point::new(vec![3.0, 2.0, 1.0]);
-    let p2 = Point::new(vec![5.0, 6.0, 7.0]);
+    let p1 = Point::new(3.0, 2.0, 1.0);
+    let p2 = Point::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, p1 - p2);
 }
 
 #[test]
 fn subtracting_two_point_refs() {
-    let p1 = Point::new(vec![3.0, 2.0, 1.0]);
-    let p2 = Point::new(vec![5.0, 6.0, 7.0]);
+    let p1 = Point::new(3.0, 2.0, 1.0);
+    let p2 = Point::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, &p1 - &p2);
 }
 
 #[test]
 fn subtracting_a_point_and_a_point_ref() {
-    let p1 = Point::new(vec![3.0, 2.0, 1.0]);
-    let p2 = Point::new(vec![5.0, 6.0, 7.0]);
+    let p1 = Point::new(3.0, 2.0, 1.0);
+    let p2 = Point::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, p1 - &p2);
 }
 
 #[test]
 fn subtracting_a_point_ref_and_a_point() {
-    let p1 = Point::new(vec![3.0, 2.0, 1.0]);
-    let p2 = Point::new(vec![5.0, 6.0, 7.0]);
+    let p1 = Point::new(3.0, 2.0, 1.0);
+    let p2 = Point::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, &p1 - p2);
 }
 
 #[test]
 fn subtracting_a_vector_from_a_point() {
-    let p = Point::new(vec![3.0, 2.0, 1.0]);
-    let v = Vector::new(vec![5.0, 6.0, 7.0]);
+    let p = Point::new(3.0, 2.0, 1.0);
+    let v = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Point::new(vec![-2.0, -4.0, -6.0]);
+    let result = Point::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, p - v);
 }
 
 #[test]
 fn subtracting_a_vector_ref_from_a_point_ref() {
-    let p = Point::new(vec![3.0, 2.0, 1.0]);
-    let v = Vector::new(vec![5.0, 6.0, 7.0]);
+    let p = Point::new(3.0, 2.0, 1.0);
+    let v = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Point::new(vec![-2.0, -4.0, -6.0]);
+    let result = Point::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, &p - &v);
 }
 
 #[test]
 fn subtracting_a_vector_ref_from_a_point() {
-    let p = Point::new(vec![3.0, 2.0, 1.0]);
-    let v = Vector::new(vec![5.0, 6.0, 7.0]);
+    let p = Point::new(3.0, 2.0, 1.0);
+    let v = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Point::new(vec![-2.0, -4.0, -6.0]);
+    let result = Point::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, p - &v);
 }
 
 #[test]
 fn subtracting_a_vector_from_a_point_ref() {
-    let p = Point::new(vec![3.0, 2.0, 1.0]);
-    let v = Vector::new(vec![5.0, 6.0, 7.0]);
+    let p = Point::new(3.0, 2.0, 1.0);
+    let v = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Point::new(vec![-2.0, -4.0, -6.0]);
+    let result = Point::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, &p - v);
 }
 
 #[test]
 fn subtracting_two_vectors() {
-    let v1 = Vector::new(vec![3.0, 2.0, 1.0]);
-    let v2 = Vector::new(vec![5.0, 6.0, 7.0]);
+    let v1 = Vector::new(3.0, 2.0, 1.0);
+    let v2 = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, v1 - v2);
 }
 
 #[test]
 fn subtracting_two_vectors_refs() {
-    let v1 = Vector::new(vec![3.0, 2.0, 1.0]);
-    let v2 = Vector::new(vec![5.0, 6.0, 7.0]);
+    let v1 = Vector::new(3.0, 2.0, 1.0);
+    let v2 = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, &v1 - &v2);
 }
 
 #[test]
 fn subtracting_a_vector_ref_from_a_vector() {
-    let v1 = Vector::new(vec![3.0, 2.0, 1.0]);
-    let v2 = Vector::new(vec![5.0, 6.0, 7.0]);
+    let v1 = Vector::new(3.0, 2.0, 1.0);
+    let v2 = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, v1 - &v2);
 }
 
 #[test]
 fn subtracting_a_vector_from_a_vector_ref() {
-    let v1 = Vector::new(vec![3.0, 2.0, 1.0]);
-    let v2 = Vector::new(vec![5.0, 6.0, 7.0]);
+    let v1 = Vector::new(3.0, 2.0, 1.0);
+    let v2 = Vector::new(5.0, 6.0, 7.0);
 
-    let result = Vector::new(vec![-2.0, -4.0, -6.0]);
+    let result = Vector::new(-2.0, -4.0, -6.0);
 
     assert_eq!(result, &v1 - v2);
 }
 
 #[test]
 fn negating_a_tuple() {
-    let t = Tuple::new(vec![1.0, -2.0, 3.0, -4.0]);
+    let t = Tuple::new(1.0, -2.0, 3.0, -4.0);
 
     let result: Vec<f32> = vec![-1.0, 2.0, -3.0, 4.0];
 
@@ -517,7 +535,7 @@ fn negating_a_tuple() {
 
 #[test]
 fn negating_a_vector() {
-    let v = Vector::new(vec![1.0, -2.0, 3.0]);
+    let v = Vector::new(1.0, -2.0, 3.0);
 
     let result: Vec<f32> = vec![-1.0, 2.0, -3.0, 0.0];
 
@@ -526,7 +544,7 @@ fn negating_a_vector() {
 
 #[test]
 fn mulitplying_a_tuple_by_a_scalar() {
-    let t = Tuple::new(vec![1.0, -2.0, 3.0, -4.0]);
+    let t = Tuple::new(1.0, -2.0, 3.0, -4.0);
 
     let result: Vec<f32> = vec![3.5, -7.0, 10.5, -14.0];
 
@@ -535,7 +553,7 @@ fn mulitplying_a_tuple_by_a_scalar() {
 
 #[test]
 fn mulitplying_a_vector_by_a_scalar() {
-    let v = Vector::new(vec![1.0, -2.0, 3.0]);
+    let v = Vector::new(1.0, -2.0, 3.0);
 
     let result: Vec<f32> = vec![3.5, -7.0, 10.5, 0.0];
 
@@ -544,7 +562,7 @@ fn mulitplying_a_vector_by_a_scalar() {
 
 #[test]
 fn mulitplying_a_tuple_by_a_fraction() {
-    let t = Tuple::new(vec![1.0, -2.0, 3.0, -4.0]);
+    let t = Tuple::new(1.0, -2.0, 3.0, -4.0);
 
     let result: Vec<f32> = vec![0.5, -1.0, 1.5, -2.0];
 
@@ -553,7 +571,7 @@ fn mulitplying_a_tuple_by_a_fraction() {
 
 #[test]
 fn mulitplying_a_vector_by_a_fraction() {
-    let v = Vector::new(vec![1.0, -2.0, 3.0]);
+    let v = Vector::new(1.0, -2.0, 3.0);
 
     let result: Vec<f32> = vec![0.5, -1.0, 1.5, 0.0];
 
@@ -562,7 +580,7 @@ fn mulitplying_a_vector_by_a_fraction() {
 
 #[test]
 fn dividing_a_tuple_by_a_scalar() {
-    let t = Tuple::new(vec![1.0, -2.0, 3.0, -4.0]);
+    let t = Tuple::new(1.0, -2.0, 3.0, -4.0);
 
     let result: Vec<f32> = vec![0.5, -1.0, 1.5, -2.0];
 
@@ -571,7 +589,7 @@ fn dividing_a_tuple_by_a_scalar() {
 
 #[test]
 fn dividing_a_vector_by_a_scalar() {
-    let v = Vector::new(vec![1.0, -2.0, 3.0]);
+    let v = Vector::new(1.0, -2.0, 3.0);
 
     let result: Vec<f32> = vec![0.5, -1.0, 1.5, 0.0];
 
@@ -580,11 +598,11 @@ fn dividing_a_vector_by_a_scalar() {
 
 #[test]
 fn computing_the_magnitude_of_a_vector() {
-    let v1 = Vector::new(vec![1.0, 0.0, 0.0]);
-    let v2 = Vector::new(vec![0.0, 1.0, 0.0]);
-    let v3 = Vector::new(vec![0.0, 0.0, 1.0]);
-    let v4 = Vector::new(vec![1.0, 2.0, 3.0]);
-    let v5 = Vector::new(vec![-1.0, -2.0, -3.0]);
+    let v1 = Vector::new(1.0, 0.0, 0.0);
+    let v2 = Vector::new(0.0, 1.0, 0.0);
+    let v3 = Vector::new(0.0, 0.0, 1.0);
+    let v4 = Vector::new(1.0, 2.0, 3.0);
+    let v5 = Vector::new(-1.0, -2.0, -3.0);
     let value: f32 = 14.0;
 
     assert_eq!(1.0, v1.magnitude());
@@ -596,8 +614,8 @@ fn computing_the_magnitude_of_a_vector() {
 
 #[test]
 fn normalizing_a_vector() {
-    let v1 = Vector::new(vec![4.0, 0.0, 0.0]);
-    let v2 = Vector::new(vec![1.0, 2.0, 3.0]);
+    let v1 = Vector::new(4.0, 0.0, 0.0);
+    let v2 = Vector::new(1.0, 2.0, 3.0);
 
     let value: f32 = 14.0;
     let m = value.sqrt();
@@ -611,7 +629,7 @@ fn normalizing_a_vector() {
 
 #[test]
 fn the_magnitude_of_a_normalized_vector() {
-    let v = Vector::new(vec![1.0, 2.0, 3.0]);
+    let v: Vector<f32> = Vector::new(1.0, 2.0, 3.0);
     let abs_diff = (1.0 - v.normalize().magnitude()).abs();
 
     assert_eq!(true, abs_diff < f32::EPSILON);
@@ -619,16 +637,16 @@ fn the_magnitude_of_a_normalized_vector() {
 
 #[test]
 fn the_dot_product_of_two_vectors() {
-    let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
-    let v2 = Vector::new(vec![2.0, 3.0, 4.0]);
+    let v1 = Vector::new(1.0, 2.0, 3.0);
+    let v2 = Vector::new(2.0, 3.0, 4.0);
 
     assert_eq!(20.0, dot(&v1, &v2));
 }
 
 #[test]
 fn the_cross_product_of_two_vectors() {
-    let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
-    let v2 = Vector::new(vec![2.0, 3.0, 4.0]);
+    let v1 = Vector::new(1.0, 2.0, 3.0);
+    let v2 = Vector::new(2.0, 3.0, 4.0);
 
     let result1 = vec![-1.0, 2.0, -1.0, 0.0];
     let result2 = vec![1.0, -2.0, 1.0, 0.0];
@@ -639,47 +657,267 @@ fn the_cross_product_of_two_vectors() {
 
 #[test]
 fn colors_are_red_green_blue_tuples() {
-    let Color { value } = Color::new(vec![-0.5, 0.4, 1.7]);
-    assert_eq!(-0.5, value[0]);
-    assert_eq!(0.4, value[1]);
-    assert_eq!(1.7, value[2]);
+    let c = Color::new(-0.5, 0.4, 1.7);
+    assert_eq!(-0.5, c.red());
+    assert_eq!(0.4, c.green());
+    assert_eq!(1.7, c.blue());
 }
 
 #[test]
 fn adding_colors() {
-    let c1 = Color::new(vec![0.9, 0.6, 0.75]);
-    let c2 = Color::new(vec![0.7, 0.1, 0.25]);
+    let c1 = Color::new(0.9, 0.6, 0.75);
+    let c2 = Color::new(0.7, 0.1, 0.25);
     let c3 = c1 + c2;
 
-    let r = Color::new(vec![1.6, 0.7, 1.0]);
+    let r = Color::new(1.6, 0.7, 1.0);
     assert_eq!(r, c3);
 }
 
 #[test]
 fn subtracting_colors() {
-    let c1 = Color::new(vec![0.9, 0.6, 0.75]);
-    let c2 = Color::new(vec![0.7, 0.1, 0.25]);
+    let c1 = Color::new(0.9, 0.6, 0.75);
+    let c2 = Color::new(0.7, 0.1, 0.25);
     let c3 = c1 - c2;
 
-    let r = Color::new(vec![0.2, 0.5, 0.5]);
+    let r = Color::new(0.2, 0.5, 0.5);
     assert_eq!(r, c3);
 }
 
 #[test]
 fn multiplying_a_color_by_a_scalar() {
-    let c1 = Color::new(vec![0.2, 0.3, 0.4]);
+    let c1 = Color::new(0.2, 0.3, 0.4);
     let c2 = c1 * 2.0;
 
-    let r = Color::new(vec![0.4, 0.6, 0.8]);
+    let r = Color::new(0.4, 0.6, 0.8);
     assert_eq!(r, c2);
 }
 
 #[test]
 fn multiplying_two_colors() {
-    let c1 = Color::new(vec![1.0, 0.2, 0.4]);
-    let c2 = Color::new(vec![0.9, 1.0, 0.1]);
+    let c1 = Color::new(1.0, 0.2, 0.4);
+    let c2 = Color::new(0.9, 1.0, 0.1);
     let c3 = c1 * c2;
 
-    let r = Color::new(vec![0.9, 0.2, 0.04]);
+    let r = Color::new(0.9, 0.2, 0.04);
     assert_eq!(r, c3);
 }
+
+#[test]
+fn it_creates_tuples_from_slices() {
+    let t = Tuple::try_from(&[1.0, 2.0, 3.0, 4.0][..]);
+    let p = Point::try_from(&[1.0, 2.0, 3.0][..]);
+    let v = Vector::try_from(&[1.0, 2.0, 3.0][..]);
+    let c = Color::try_from(&[0.1, 0.2, 0.3][..]);
+
+    assert_eq!(Ok(Tuple::new(1.0, 2.0, 3.0, 4.0)), t);
+    assert_eq!(Ok(Point::new(1.0, 2.0, 3.0)), p);
+    assert_eq!(Ok(Vector::new(1.0, 2.0, 3.0)), v);
+    assert_eq!(Ok(Color::new(0.1, 0.2, 0.3)), c);
+}
+
+#[test]
+fn it_rejects_slices_of_the_wrong_length() {
+    let p = Point::try_from(&[1.0, 2.0, 3.0, 1.0][..]);
+    let t = Tuple::try_from(&[1.0, 2.0][..]);
+
+    assert_eq!(
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            found: 4
+        }),
+        p
+    );
+    assert_eq!(
+        Err(Error::DimensionMismatch {
+            expected: 4,
+            found: 2
+        }),
+        t
+    );
+}
+
+#[test]
+fn it_converts_tuples_with_a_matching_w_component() {
+    let p = Point::try_from(Tuple::new(4.0, -4.0, 3.0, 1.0));
+    let v = Vector::try_from(Tuple::new(4.0, -4.0, 3.0, 0.0));
+
+    assert_eq!(Ok(Point::new(4.0, -4.0, 3.0)), p);
+    assert_eq!(Ok(Vector::new(4.0, -4.0, 3.0)), v);
+}
+
+#[test]
+fn it_rejects_tuples_with_a_mismatching_w_component() {
+    let p = Point::try_from(Tuple::new(4.0, -4.0, 3.0, 0.0));
+    let v = Vector::try_from(Tuple::new(4.0, -4.0, 3.0, 1.0));
+
+    assert_eq!(
+        Err(Error::InvalidW {
+            expected: 1.0,
+            found: 0.0
+        }),
+        p
+    );
+    assert_eq!(
+        Err(Error::InvalidW {
+            expected: 0.0,
+            found: 1.0
+        }),
+        v
+    );
+}
+
+#[test]
+fn it_converts_points_and_vectors_into_tuples() {
+    let p = Point::new(4.0, -4.0, 3.0);
+    let v = Vector::new(4.0, -4.0, 3.0);
+
+    assert_eq!(Tuple::new(4.0, -4.0, 3.0, 1.0), Tuple::from(p));
+    assert_eq!(Tuple::new(4.0, -4.0, 3.0, 0.0), Tuple::from(v));
+}
+
+#[test]
+fn it_accesses_the_components_of_a_tuple() {
+    let t = Tuple::new(4.3, -4.2, 3.1, 1.0);
+
+    assert_eq!(4.3, t.x());
+    assert_eq!(-4.2, t.y());
+    assert_eq!(3.1, t.z());
+    assert_eq!(1.0, t.w());
+}
+
+#[test]
+fn reflecting_a_vector_approaching_at_45_degrees() {
+    let v = Vector::new(1.0, -1.0, 0.0);
+    let n = Vector::new(0.0, 1.0, 0.0);
+
+    assert_eq!(Vector::new(1.0, 1.0, 0.0), v.reflect(&n));
+}
+
+#[test]
+fn reflecting_a_vector_off_a_slanted_surface() {
+    let v = Vector::new(0.0, -1.0, 0.0);
+    let n = Vector::new(2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0, 0.0);
+
+    assert_eq!(Vector::new(1.0, 0.0, 0.0), v.reflect(&n));
+}
+
+#[test]
+fn refracting_a_vector_at_perpendicular_incidence() {
+    let v = Vector::new(0.0, 0.0, 1.0);
+    let n = Vector::new(0.0, 0.0, -1.0);
+
+    assert_eq!(Some(Vector::new(0.0, 0.0, 1.0)), v.refract(&n, 1.0 / 1.5));
+}
+
+#[test]
+fn refracting_a_vector_entering_a_denser_medium() {
+    let v = Vector::new(2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt()) / 2.0, 0.0);
+    let n = Vector::new(0.0, 1.0, 0.0);
+
+    let sin_t = 2.0_f64.sqrt() / 2.0 / 1.5;
+    let result = Vector::new(sin_t, -(1.0 - sin_t.powi(2)).sqrt(), 0.0);
+
+    assert_eq!(Some(result), v.refract(&n, 1.0 / 1.5));
+}
+
+#[test]
+fn refracting_a_vector_under_total_internal_reflection() {
+    let v = Vector::new(2.0_f64.sqrt() / 2.0, -(2.0_f64.sqrt()) / 2.0, 0.0);
+    let n = Vector::new(0.0, 1.0, 0.0);
+
+    assert_eq!(None, v.refract(&n, 1.5));
+}
+
+#[test]
+fn the_schlick_approximation_under_total_internal_reflection() {
+    let eyev = Vector::new(0.0, 2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0);
+    let normal = Vector::new(0.0, 0.0, 1.0);
+
+    assert_eq!(1.0, schlick(&eyev, &normal, 1.5, 1.0));
+}
+
+#[test]
+fn the_schlick_approximation_with_a_perpendicular_viewing_angle() {
+    let eyev = Vector::new(0.0, -1.0, 0.0);
+    let normal = Vector::new(0.0, -1.0, 0.0);
+
+    assert_approx_eq!(0.04, schlick(&eyev, &normal, 1.5, 1.0));
+}
+
+#[test]
+fn the_schlick_approximation_with_small_angle_and_n2_greater_than_n1() {
+    let eyev = Vector::new(0.0, 0.0, -1.0);
+    let normal = Vector::new(0.0, 0.99, -(1.0_f64 - 0.99 * 0.99).sqrt());
+
+    assert_approx_eq!(0.48873, schlick(&eyev, &normal, 1.0, 1.5), epsilon = 0.0001);
+}
+
+#[test]
+fn clamping_a_color() {
+    let c = Color::new(-0.5, 0.4, 1.7);
+
+    assert_eq!(Color::new(0.0, 0.4, 1.0), c.clamp());
+}
+
+#[test]
+fn quantizing_a_color_to_8_bits() {
+    assert_eq!([0, 102, 255], Color::new(-0.5, 0.4, 1.7).to_rgb8());
+    assert_eq!([128, 127, 1], Color::new(0.5, 0.498, 0.002).to_rgb8());
+    assert_eq!([0, 255, 0], Color::new(0.0, 1.0, 0.0).to_rgb8());
+}
+
+#[test]
+fn converting_a_color_between_linear_and_srgb() {
+    let linear = Color::new(0.0, 0.5, 1.0);
+    let srgb = Color::new(0.0, 0.735357, 1.0);
+
+    assert_eq!(srgb, linear.to_srgb());
+    assert_eq!(linear, srgb.to_linear());
+    assert_eq!(
+        Color::new(0.001 * 12.92, 0.001 * 12.92, 0.001 * 12.92),
+        Color::new(0.001, 0.001, 0.001).to_srgb()
+    );
+}
+
+#[test]
+fn round_tripping_a_color_through_srgb() {
+    let c = Color::new(0.02, 0.2, 0.8);
+
+    assert_eq!(c, c.to_srgb().to_linear());
+}
+
+#[test]
+fn computing_the_luminance_of_a_color() {
+    assert_approx_eq!(1.0, Color::new(1.0, 1.0, 1.0).luminance());
+    assert_approx_eq!(0.7152, Color::new(0.0, 1.0, 0.0).luminance());
+    assert_approx_eq!(0.0, Color::new(0.0, 0.0, 0.0).luminance());
+}
+
+#[test]
+fn creating_colors_from_hex_strings() {
+    assert_eq!(
+        Ok(Color::new(1.0, 128.0 / 255.0, 0.0)),
+        Color::from_hex("#ff8000")
+    );
+    assert_eq!(
+        Ok(Color::new(10.0 / 255.0, 1.0, 171.0 / 255.0)),
+        Color::from_hex("0AffAB")
+    );
+    assert_eq!(Ok(Color::new(1.0, 0.0, 0.6)), Color::from_hex("#f09"));
+}
+
+#[test]
+fn rejecting_invalid_hex_strings() {
+    assert_eq!(
+        Err(Error::InvalidHex("#ff80".to_string())),
+        Color::<f64>::from_hex("#ff80")
+    );
+    assert_eq!(
+        Err(Error::InvalidHex("#gg8000".to_string())),
+        Color::<f64>::from_hex("#gg8000")
+    );
+    assert_eq!(
+        Err(Error::InvalidHex("+f8000".to_string())),
+        Color::<f64>::from_hex("+f8000")
+    );
+}